@@ -0,0 +1,254 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    fn from_string(input: &str) -> Result<Self, String> {
+        match input {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            "both" => Ok(Part::Both),
+            _ => Err(format!("invalid part '{input}', expected 1, 2 or both")),
+        }
+    }
+
+    pub fn includes_part_1(&self) -> bool {
+        matches!(self, Part::One | Part::Both)
+    }
+
+    pub fn includes_part_2(&self) -> bool {
+        matches!(self, Part::Two | Part::Both)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Default,
+    File(String),
+    Stdin,
+}
+
+impl InputSource {
+    fn from_string(input: &str) -> Self {
+        match input {
+            "-" => InputSource::Stdin,
+            _ => InputSource::File(input.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DaySelection {
+    Single(u8),
+    All,
+}
+
+const DEFAULT_ANSWERS_PATH: &str = "answers.toml";
+
+#[derive(Debug, PartialEq)]
+pub struct Arguments {
+    pub days: DaySelection,
+    pub part: Part,
+    pub input: InputSource,
+    pub verify: bool,
+    pub explain: bool,
+    pub answers: String,
+}
+
+impl Arguments {
+    pub fn for_day(day: u8) -> Self {
+        Self {
+            days: DaySelection::Single(day),
+            part: Part::Both,
+            input: InputSource::Default,
+            verify: false,
+            explain: false,
+            answers: DEFAULT_ANSWERS_PATH.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Help,
+    Solve(Arguments),
+    Assemble(InputSource),
+}
+
+fn parse_day(input: &str) -> Result<u8, String> {
+    input
+        .parse()
+        .map_err(|_| format!("invalid day '{input}', expected a number"))
+}
+
+fn expect_value(flag: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or(format!("missing value for {flag}"))
+}
+
+impl Command {
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut day: Option<u8> = None;
+        let mut all = false;
+        let mut part = Part::Both;
+        let mut input = InputSource::Default;
+        let mut verify = false;
+        let mut explain = false;
+        let mut answers = DEFAULT_ANSWERS_PATH.to_string();
+
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(Command::Help),
+                "--assemble" => {
+                    let source = InputSource::from_string(&expect_value(&arg, args.next())?);
+                    return Ok(Command::Assemble(source));
+                }
+                "-d" | "--day" => day = Some(parse_day(&expect_value(&arg, args.next())?)?),
+                "-p" | "--part" => part = Part::from_string(&expect_value(&arg, args.next())?)?,
+                "-i" | "--input" => {
+                    input = InputSource::from_string(&expect_value(&arg, args.next())?)
+                }
+                "-a" | "--all" => all = true,
+                "-v" | "--verify" => verify = true,
+                "-e" | "--explain" => explain = true,
+                "--answers" => answers = expect_value(&arg, args.next())?,
+                _ => return Err(format!("unknown argument '{arg}'")),
+            }
+        }
+
+        let days = match (day, all) {
+            (Some(_), true) => return Err("--day and --all cannot be combined".to_string()),
+            (Some(day), false) => DaySelection::Single(day),
+            (None, true) => DaySelection::All,
+            (None, false) => return Err("either --day or --all is required".to_string()),
+        };
+
+        if days == DaySelection::All && input != InputSource::Default {
+            return Err("--input cannot be combined with --all".to_string());
+        }
+
+        Ok(Command::Solve(Arguments {
+            days,
+            part,
+            input,
+            verify,
+            explain,
+            answers,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(input: &str) -> Vec<String> {
+        input.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_day_with_defaults() {
+        let result = Command::from_args(args("--day 7"));
+
+        assert_eq!(result, Ok(Command::Solve(Arguments::for_day(7))));
+    }
+
+    #[test]
+    fn parses_part_and_input() {
+        let result = Command::from_args(args("-d 17 --part 2 --input other.txt"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Solve(Arguments {
+                days: DaySelection::Single(17),
+                part: Part::Two,
+                input: InputSource::File("other.txt".to_string()),
+                ..Arguments::for_day(17)
+            }))
+        );
+
+        let result = Command::from_args(args("--day 1 --input -"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Solve(Arguments {
+                input: InputSource::Stdin,
+                ..Arguments::for_day(1)
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_all_days() {
+        let result = Command::from_args(args("--all --part 1"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Solve(Arguments {
+                days: DaySelection::All,
+                part: Part::One,
+                ..Arguments::for_day(1)
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_verify_mode() {
+        let result = Command::from_args(args("--all --verify --answers other.toml"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Solve(Arguments {
+                days: DaySelection::All,
+                verify: true,
+                answers: "other.toml".to_string(),
+                ..Arguments::for_day(1)
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_explain_mode() {
+        let result = Command::from_args(args("-d 19 --explain"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Solve(Arguments {
+                explain: true,
+                ..Arguments::for_day(19)
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_assemble_mode() {
+        let result = Command::from_args(args("--assemble program.txt"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Assemble(InputSource::File(
+                "program.txt".to_string()
+            )))
+        );
+
+        let result = Command::from_args(args("--assemble -"));
+        assert_eq!(result, Ok(Command::Assemble(InputSource::Stdin)));
+
+        assert!(Command::from_args(args("--assemble")).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert!(Command::from_args(args("")).is_err());
+        assert!(Command::from_args(args("--day")).is_err());
+        assert!(Command::from_args(args("--day x")).is_err());
+        assert!(Command::from_args(args("--day 1 --part 3")).is_err());
+        assert!(Command::from_args(args("--day 1 --all")).is_err());
+        assert!(Command::from_args(args("--all --input -")).is_err());
+        assert!(Command::from_args(args("--day 1 --verbose")).is_err());
+    }
+}
@@ -0,0 +1,18 @@
+mod arguments;
+
+pub use arguments::{Arguments, Command, DaySelection, InputSource, Part};
+
+pub const USAGE: &str = "usage: advent_of_code_2024 (--day <n> | --all) [options]
+       advent_of_code_2024 --assemble <path>
+
+  -d, --day <n>        solve a single day
+  -a, --all            solve every registered day using its default input
+  -p, --part <part>    solve part 1, part 2 or both (default: both)
+  -i, --input <path>   read the input from <path>, or from stdin when '-'
+  -v, --verify         check each answer against the expected answers file
+      --answers <path> expected answers used by --verify (default: answers.toml)
+  -e, --explain        print extra detail for days that support it
+      --assemble <path>
+                       print the day 17 'Program:' line for the mnemonics in
+                       <path>, or in stdin when '-'
+  -h, --help           print this message";
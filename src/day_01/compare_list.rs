@@ -1,3 +1,4 @@
+use crate::util::{parse_number, Error};
 use std::{cmp::Ordering, collections::HashMap};
 
 pub struct CompareList {
@@ -6,29 +7,29 @@ pub struct CompareList {
 }
 
 impl CompareList {
-    pub fn from_string(input: String) -> Self {
+    pub fn from_string(input: String) -> Result<Self, Error> {
         let mut lhs = Vec::new();
         let mut rhs = Vec::new();
 
-        input.lines().for_each(|line| {
-            line.split(" ").for_each(|entry| {
+        for (index, line) in input.lines().enumerate() {
+            for entry in line.split(" ") {
                 if entry.is_empty() {
-                    return;
+                    continue;
                 }
 
-                let number = entry.parse().unwrap();
+                let number = parse_number(entry).map_err(|error| error.at_line(index + 1))?;
 
                 match lhs.len() == rhs.len() {
                     true => lhs.push(number),
                     false => rhs.push(number),
                 }
-            });
-        });
+            }
+        }
 
-        Self {
+        Ok(Self {
             lhs: lhs.to_vec(),
             rhs: rhs.to_vec(),
-        }
+        })
     }
 }
 
@@ -1,30 +1,26 @@
 mod compare_list;
 
-use crate::util::read_input;
+use crate::util::Error;
 use compare_list::CompareList;
 
-fn list_difference_from_input(input: String) -> u32 {
-    let compare_list = CompareList::from_string(input);
+fn list_difference_from_input(input: String) -> Result<u32, Error> {
+    let compare_list = CompareList::from_string(input)?;
 
-    compare_list.find_difference()
+    Ok(compare_list.find_difference())
 }
 
-pub fn solve_part_1() -> u32 {
-    let input = read_input("src/day_01/input.txt");
-
-    list_difference_from_input(input)
+pub fn solve_part_1(input: &str) -> Result<u32, Error> {
+    list_difference_from_input(input.to_string())
 }
 
-fn list_similarity_from_input(input: String) -> u32 {
-    let compare_list = CompareList::from_string(input);
+fn list_similarity_from_input(input: String) -> Result<u32, Error> {
+    let compare_list = CompareList::from_string(input)?;
 
-    compare_list.find_similarity()
+    Ok(compare_list.find_similarity())
 }
 
-pub fn solve_part_2() -> u32 {
-    let input = read_input("src/day_01/input.txt");
-
-    list_similarity_from_input(input)
+pub fn solve_part_2(input: &str) -> Result<u32, Error> {
+    list_similarity_from_input(input.to_string())
 }
 
 #[cfg(test)]
@@ -35,7 +31,7 @@ mod test {
     fn finds_difference() {
         let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
 
-        let result = list_difference_from_input(input.to_string());
+        let result = list_difference_from_input(input.to_string()).unwrap();
         assert_eq!(result, 11);
     }
 
@@ -43,7 +39,7 @@ mod test {
     fn finds_similarity() {
         let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
 
-        let result = list_similarity_from_input(input.to_string());
+        let result = list_similarity_from_input(input.to_string()).unwrap();
         assert_eq!(result, 31);
     }
 }
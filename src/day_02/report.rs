@@ -1,3 +1,5 @@
+use crate::util::{parse_lines, parse_number, Error};
+
 pub struct Report {
     levels: Vec<i32>,
 }
@@ -9,19 +11,14 @@ enum Sign {
 }
 
 impl Report {
-    pub fn from_text(input: &str) -> Self {
+    pub fn from_text(input: &str) -> Result<Self, Error> {
         let levels = input
             .split(" ")
-            .filter_map(|entry| {
-                if entry.is_empty() {
-                    return None;
-                }
+            .filter(|entry| !entry.is_empty())
+            .map(parse_number)
+            .collect::<Result<_, _>>()?;
 
-                Some(entry.parse().unwrap())
-            })
-            .collect();
-
-        Self { levels }
+        Ok(Self { levels })
     }
 
     pub fn from_levels(levels: Vec<i32>) -> Self {
@@ -83,8 +80,8 @@ impl Report {
     }
 }
 
-pub fn reports_from_text(text: String) -> Vec<Report> {
-    text.lines().map(|line| Report::from_text(line)).collect()
+pub fn reports_from_text(text: String) -> Result<Vec<Report>, Error> {
+    parse_lines(&text, Report::from_text)
 }
 
 fn array_skip_index(array: &Vec<i32>, skip: usize) -> Vec<i32> {
@@ -106,35 +103,47 @@ mod test {
     #[test]
     fn verifies_report_safety() {
         let input = "7 6 4 2 1";
-        assert_eq!(Report::from_text(input).is_safe(), true);
+        assert_eq!(Report::from_text(input).unwrap().is_safe(), true);
 
         let input = "1 2 7 8 9";
-        assert_eq!(Report::from_text(input).is_safe(), false);
+        assert_eq!(Report::from_text(input).unwrap().is_safe(), false);
 
         let input = "8 6 4 4 1";
-        assert_eq!(Report::from_text(input).is_safe(), false);
+        assert_eq!(Report::from_text(input).unwrap().is_safe(), false);
 
         let input = "1 3 6 7 9";
-        assert_eq!(Report::from_text(input).is_safe(), true);
+        assert_eq!(Report::from_text(input).unwrap().is_safe(), true);
     }
 
     #[test]
     fn verifies_report_safety_with_dampener() {
         let input = "1 2 7 8 9";
-        assert_eq!(Report::from_text(input).is_safe(), false);
-        assert_eq!(Report::from_text(input).is_safe_with_dampener(), false);
+        assert_eq!(Report::from_text(input).unwrap().is_safe(), false);
+        assert_eq!(
+            Report::from_text(input).unwrap().is_safe_with_dampener(),
+            false
+        );
 
         let input = "1 3 2 4 5";
-        assert_eq!(Report::from_text(input).is_safe(), false);
-        assert_eq!(Report::from_text(input).is_safe_with_dampener(), true);
+        assert_eq!(Report::from_text(input).unwrap().is_safe(), false);
+        assert_eq!(
+            Report::from_text(input).unwrap().is_safe_with_dampener(),
+            true
+        );
 
         let input = "8 6 4 4 1";
-        assert_eq!(Report::from_text(input).is_safe(), false);
-        assert_eq!(Report::from_text(input).is_safe_with_dampener(), true);
+        assert_eq!(Report::from_text(input).unwrap().is_safe(), false);
+        assert_eq!(
+            Report::from_text(input).unwrap().is_safe_with_dampener(),
+            true
+        );
 
         let input = "1 3 6 7 9";
-        assert_eq!(Report::from_text(input).is_safe(), true);
-        assert_eq!(Report::from_text(input).is_safe_with_dampener(), true);
+        assert_eq!(Report::from_text(input).unwrap().is_safe(), true);
+        assert_eq!(
+            Report::from_text(input).unwrap().is_safe_with_dampener(),
+            true
+        );
     }
 
     #[test]
@@ -1,7 +1,7 @@
 mod enabled_switch;
 mod multiplication;
 
-use crate::util::read_input;
+use crate::util::Error;
 use enabled_switch::EnabledSwitch;
 use multiplication::Multiplication;
 
@@ -40,16 +40,12 @@ fn perform_enabled_multiplications(input: String) -> i64 {
     })
 }
 
-pub fn solve_part_1() -> i64 {
-    let input = read_input("src/day_03/input.txt");
-
-    perform_multiplications(input)
+pub fn solve_part_1(input: &str) -> Result<i64, Error> {
+    Ok(perform_multiplications(input.to_string()))
 }
 
-pub fn solve_part_2() -> i64 {
-    let input = read_input("src/day_03/input.txt");
-
-    perform_enabled_multiplications(input)
+pub fn solve_part_2(input: &str) -> Result<i64, Error> {
+    Ok(perform_enabled_multiplications(input.to_string()))
 }
 
 #[cfg(test)]
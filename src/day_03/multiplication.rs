@@ -31,8 +31,8 @@ impl Multiplication {
         }
 
         Some(Self {
-            lhs: lhs.iter().collect::<String>().parse().unwrap(),
-            rhs: rhs.iter().collect::<String>().parse().unwrap(),
+            lhs: lhs.iter().collect::<String>().parse().ok()?,
+            rhs: rhs.iter().collect::<String>().parse().ok()?,
         })
     }
 }
@@ -1,25 +1,21 @@
 mod searcher;
 
-use crate::util::read_input;
+use crate::util::Error;
 
 fn find_xmas(input: String) -> u32 {
     searcher::find_instances(input, "XMAS")
 }
 
-pub fn solve_part_1() -> u32 {
-    let input = read_input("src/day_04/input.txt");
-
-    find_xmas(input)
+pub fn solve_part_1(input: &str) -> Result<u32, Error> {
+    Ok(find_xmas(input.to_string()))
 }
 
 fn find_cross_mas(input: String) -> u32 {
     searcher::find_cross_instances(input, "MAS")
 }
 
-pub fn solve_part_2() -> u32 {
-    let input = read_input("src/day_04/input.txt");
-
-    find_cross_mas(input)
+pub fn solve_part_2(input: &str) -> Result<u32, Error> {
+    Ok(find_cross_mas(input.to_string()))
 }
 
 #[cfg(test)]
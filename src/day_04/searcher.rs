@@ -19,7 +19,7 @@ impl WordBounds {
             .collect();
 
         let size_y = text.lines().count() as isize;
-        let size_x = text.lines().nth(0).unwrap().chars().count() as isize;
+        let size_x = text.lines().next().unwrap_or_default().chars().count() as isize;
 
         Self {
             entries,
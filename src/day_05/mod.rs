@@ -2,66 +2,58 @@ mod ordering;
 
 use ordering::PageOrderingRules;
 
-use crate::util::{divide_integer, read_input};
+use crate::util::{divide_integer, expect_component, line_after, parse_lines, parse_number, Error};
 
-fn text_to_numbers(text: &str) -> Vec<u32> {
-    text.split(",")
-        .map(|entry| entry.parse().unwrap())
-        .collect()
+fn text_to_numbers(text: &str) -> Result<Vec<u32>, Error> {
+    text.split(",").map(parse_number).collect()
 }
 
-fn verify_page_ordering(input: String) -> u32 {
+fn split_input(input: &str) -> Result<(PageOrderingRules, Vec<Vec<u32>>), Error> {
     let mut components = input.split("\n\n");
-    let rules_text = components.nth(0).unwrap();
-    let print_text = components.nth(0).unwrap();
+    let rules_text = expect_component(components.next(), input, "page ordering rules")?;
+    let print_text = expect_component(components.next(), input, "a blank line before the updates")?;
 
-    let rules = PageOrderingRules::from_string(rules_text);
+    let rules = PageOrderingRules::from_string(rules_text)?;
+    let print_orders = parse_lines(print_text, text_to_numbers)
+        .map_err(|error| error.at_line(line_after(rules_text)))?;
 
-    print_text
-        .lines()
-        .filter_map(|line| {
-            let print_order: Vec<u32> = text_to_numbers(line);
-
-            match rules.is_correct_order(&print_order) {
-                true => Some(print_order[divide_integer(print_order.len(), 2.0)]),
-                false => None,
-            }
-        })
-        .sum()
+    Ok((rules, print_orders))
 }
 
-fn correct_page_ordering(input: String) -> u32 {
-    let mut components = input.split("\n\n");
-    let rules_text = components.nth(0).unwrap();
-    let print_text = components.nth(0).unwrap();
+fn verify_page_ordering(input: String) -> Result<u32, Error> {
+    let (rules, print_orders) = split_input(&input)?;
 
-    let rules = PageOrderingRules::from_string(rules_text);
+    Ok(print_orders
+        .iter()
+        .map(|print_order| match rules.is_correct_order(print_order) {
+            true => print_order[divide_integer(print_order.len(), 2.0)],
+            false => 0,
+        })
+        .sum())
+}
 
-    print_text
-        .lines()
-        .map(|line| {
-            let print_order: Vec<u32> = text_to_numbers(line);
+fn correct_page_ordering(input: String) -> Result<u32, Error> {
+    let (rules, print_orders) = split_input(&input)?;
 
-            if rules.is_correct_order(&print_order) {
+    Ok(print_orders
+        .iter()
+        .map(|print_order| {
+            if rules.is_correct_order(print_order) {
                 return 0;
             }
 
-            let print_order = rules.order_correctly(&print_order);
+            let print_order = rules.order_correctly(print_order);
             print_order[divide_integer(print_order.len(), 2.0)]
         })
-        .sum()
+        .sum())
 }
 
-pub fn solve_part_1() -> u32 {
-    let input = read_input("src/day_05/input.txt");
-
-    verify_page_ordering(input)
+pub fn solve_part_1(input: &str) -> Result<u32, Error> {
+    verify_page_ordering(input.to_string())
 }
 
-pub fn solve_part_2() -> u32 {
-    let input = read_input("src/day_05/input.txt");
-
-    correct_page_ordering(input)
+pub fn solve_part_2(input: &str) -> Result<u32, Error> {
+    correct_page_ordering(input.to_string())
 }
 
 #[cfg(test)]
@@ -71,7 +63,7 @@ mod tests {
     #[test]
     fn identifies_correct_print_orders() {
         let input = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13\n\n75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n61,13,29\n97,13,75,29,47";
-        let result = verify_page_ordering(input.to_string());
+        let result = verify_page_ordering(input.to_string()).unwrap();
 
         assert_eq!(result, 143);
     }
@@ -79,7 +71,7 @@ mod tests {
     #[test]
     fn corrects_print_orders() {
         let input = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13\n\n75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n61,13,29\n97,13,75,29,47";
-        let result = correct_page_ordering(input.to_string());
+        let result = correct_page_ordering(input.to_string()).unwrap();
 
         assert_eq!(result, 123);
     }
@@ -1,18 +1,23 @@
+use crate::util::{parse_lines, parse_number, Error};
 use std::collections::HashMap;
 
+fn rule_from_string(input: &str) -> Result<(u32, u32), Error> {
+    let (index, rule) = input
+        .split_once("|")
+        .ok_or(Error::parse(input, "<page>|<page>"))?;
+
+    Ok((parse_number(index)?, parse_number(rule)?))
+}
+
 pub struct PageOrderingRules {
     after_rules: HashMap<u32, Vec<u32>>,
 }
 
 impl PageOrderingRules {
-    pub fn from_string(input: &str) -> Self {
+    pub fn from_string(input: &str) -> Result<Self, Error> {
         let mut after_rules: HashMap<u32, Vec<u32>> = HashMap::new();
 
-        input.lines().for_each(|line| {
-            let mut components = line.split("|");
-            let index: u32 = components.nth(0).unwrap().parse().unwrap();
-            let rule: u32 = components.nth(0).unwrap().parse().unwrap();
-
+        for (index, rule) in parse_lines(input, rule_from_string)? {
             let mut index_rules: Vec<u32> = Vec::from([rule]);
 
             match after_rules.get(&index) {
@@ -23,9 +28,9 @@ impl PageOrderingRules {
             };
 
             after_rules.insert(index, index_rules);
-        });
+        }
 
-        Self { after_rules }
+        Ok(Self { after_rules })
     }
 }
 
@@ -101,7 +106,7 @@ mod tests {
         let rules_input = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13";
         let print_line = Vec::from([75, 47, 61, 53, 29]);
 
-        let rules = PageOrderingRules::from_string(rules_input);
+        let rules = PageOrderingRules::from_string(rules_input).unwrap();
         let result = rules.is_correct_order(&print_line);
 
         assert_eq!(result, true);
@@ -110,7 +115,7 @@ mod tests {
     #[test]
     fn correctly_orders_print() {
         let rules_input = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13";
-        let rules = PageOrderingRules::from_string(rules_input);
+        let rules = PageOrderingRules::from_string(rules_input).unwrap();
 
         let print_line = Vec::from([75, 97, 47, 61, 53]);
         let result = rules.order_correctly(&print_line);
@@ -1,5 +1,5 @@
-use crate::util::{move_steps_in_direction, Direction, Position};
-use std::collections::{HashMap, HashSet};
+use crate::util::{move_steps_in_direction, Direction, Error, Grid, Position};
+use std::collections::HashSet;
 
 #[derive(Clone, Copy)]
 enum MapFeature {
@@ -7,36 +7,77 @@ enum MapFeature {
     Obstacle,
 }
 
-#[derive(Clone)]
+const HEADINGS: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
 pub struct Map2D {
-    tiles: HashMap<Position, MapFeature>,
+    tiles: Grid<MapFeature>,
     start: Position,
+    jumps: Grid<[Option<Position>; 4]>,
 }
 
 impl Map2D {
-    pub fn from_string(text: &str) -> Self {
-        let mut tiles = HashMap::new();
-        let mut start = (0, 0);
-
-        text.lines().enumerate().for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, character)| {
-                let x = x as isize;
-                let y = y as isize;
-
-                let feature = match character {
-                    '#' => MapFeature::Obstacle,
-                    '^' => {
-                        start = (x, y);
-                        MapFeature::Empty
-                    }
-                    _ => MapFeature::Empty,
-                };
-
-                tiles.insert((x, y), feature);
-            });
+    pub fn from_string(text: &str) -> Result<Self, Error> {
+        let characters = Grid::from_characters(text, ".#^")?;
+
+        let start = characters.find(&'^').unwrap_or_default();
+        let tiles = characters.map(|character| match character {
+            '#' => MapFeature::Obstacle,
+            _ => MapFeature::Empty,
         });
 
-        Self { tiles, start }
+        Ok(Self {
+            jumps: jump_table(&tiles),
+            tiles,
+            start,
+        })
+    }
+}
+
+fn heading_index(heading: &Direction) -> usize {
+    match heading {
+        Direction::N => 0,
+        Direction::E => 1,
+        Direction::S => 2,
+        _ => 3,
+    }
+}
+
+fn jump_table(tiles: &Grid<MapFeature>) -> Grid<[Option<Position>; 4]> {
+    let mut jumps = tiles.map(|_| [None; 4]);
+
+    for (index, heading) in HEADINGS.iter().enumerate() {
+        let (dx, dy) = heading.step_2d();
+        let mut positions: Vec<Position> = tiles.iter().map(|(position, _)| position).collect();
+        positions.sort_by_key(|(x, y)| -(x * dx + y * dy));
+
+        for position in positions {
+            let next = move_steps_in_direction(&position, 1, heading);
+
+            let stop = match tiles.get(&next) {
+                None => None,
+                Some(MapFeature::Obstacle) => Some(position),
+                Some(MapFeature::Empty) => jumps.get(&next).and_then(|stops| stops[index]),
+            };
+
+            if let Some(mut stops) = jumps.get(&position).copied() {
+                stops[index] = stop;
+                jumps.set(&position, stops);
+            }
+        }
+    }
+
+    jumps
+}
+
+fn distance_ahead(from: &Position, to: &Position, heading: &Direction) -> Option<isize> {
+    let (dx, dy) = heading.step_2d();
+    let (x, y) = (to.0 - from.0, to.1 - from.1);
+
+    let distance = x * dx + y * dy;
+
+    match x * dy == y * dx && distance > 0 {
+        true => Some(distance),
+        false => None,
     }
 }
 
@@ -62,7 +103,7 @@ impl Map2D {
         let mut position = self.start.clone();
         let mut positions = HashSet::new();
 
-        while let Some(_) = self.tiles.get(&position) {
+        while self.tiles.contains(&position) {
             positions.insert(position);
             (position, heading) = self.step_once(&position, &heading);
         }
@@ -75,31 +116,86 @@ impl Map2D {
 
         steps
             .into_iter()
-            .filter(|step| self.is_loop_with_obstacle(*step))
+            .filter(|step| step != &self.start)
+            .filter(|step| self.is_loop_with_obstacle(step))
             .count()
     }
 
-    pub fn is_loop(&self) -> bool {
-        let mut heading = Direction::N;
-        let mut position = self.start.clone();
-        let mut positions: HashSet<(Position, Direction)> = HashSet::new();
+    fn jump(
+        &self,
+        position: &Position,
+        heading: &Direction,
+        obstacle: Option<&Position>,
+    ) -> Option<Position> {
+        let stop = self.jumps.get(position)?[heading_index(heading)];
+
+        let Some(obstacle) = obstacle else {
+            return stop;
+        };
+
+        let Some(distance) = distance_ahead(position, obstacle, heading) else {
+            return stop;
+        };
+
+        let blocked_earlier = match stop {
+            Some(stop) => distance_ahead(position, &stop, heading).unwrap_or_default() >= distance,
+            None => self.tiles.contains(obstacle),
+        };
+
+        match blocked_earlier {
+            true => Some(move_steps_in_direction(position, distance - 1, heading)),
+            false => stop,
+        }
+    }
 
-        while let Some(_) = self.tiles.get(&position) {
-            positions.insert((position, heading));
-            (position, heading) = self.step_once(&position, &heading);
+    pub fn is_loop_with_obstacle(&self, obstacle: &Position) -> bool {
+        let mut state = (self.start, Direction::N);
+        let mut visited: HashSet<(Position, Direction)> = HashSet::new();
+
+        while let Some(stop) = self.jump(&state.0, &state.1, Some(obstacle)) {
+            state = (stop, state.1.rotate_90_degress_clockwise());
 
-            if positions.contains(&(position, heading)) {
+            if !visited.insert(state) {
                 return true;
             }
         }
 
         false
     }
+}
 
-    pub fn is_loop_with_obstacle(&self, obstacle: Position) -> bool {
-        let mut new_map = self.clone();
-        new_map.tiles.insert(obstacle, MapFeature::Obstacle);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jumps_to_next_obstacle() {
+        let input = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
+        let map = Map2D::from_string(input).unwrap();
+
+        assert_eq!(map.jump(&(4, 6), &Direction::N, None), Some((4, 1)));
+        assert_eq!(map.jump(&(4, 1), &Direction::E, None), Some((8, 1)));
+        assert_eq!(map.jump(&(4, 6), &Direction::S, None), None);
+        assert_eq!(
+            map.jump(&(4, 6), &Direction::N, Some(&(4, 3))),
+            Some((4, 4))
+        );
+        assert_eq!(
+            map.jump(&(4, 6), &Direction::S, Some(&(4, 9))),
+            Some((4, 8))
+        );
+    }
+
+    #[test]
+    fn detects_loops_with_obstacle() {
+        let input = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
+        let map = Map2D::from_string(input).unwrap();
+
+        for obstacle in [(3, 6), (6, 7), (7, 7), (1, 8), (3, 8), (7, 9)] {
+            assert!(map.is_loop_with_obstacle(&obstacle));
+        }
 
-        new_map.is_loop()
+        assert!(!map.is_loop_with_obstacle(&(4, 5)));
+        assert!(!map.is_loop_with_obstacle(&(9, 9)));
     }
 }
@@ -1,34 +1,28 @@
 mod map_2d;
 
+use crate::util::Error;
 use map_2d::Map2D;
 
-use crate::util::read_input;
-
-fn count_guard_steps(input: &str) -> usize {
-    let map = Map2D::from_string(input);
+fn count_guard_steps(input: &str) -> Result<usize, Error> {
+    let map = Map2D::from_string(input)?;
 
     let steps = map.steps_to_exit();
 
-    steps.len()
+    Ok(steps.len())
 }
 
-fn count_obstacles_for_loop(input: &str) -> usize {
-    let map = Map2D::from_string(input);
+fn count_obstacles_for_loop(input: &str) -> Result<usize, Error> {
+    let map = Map2D::from_string(input)?;
 
-    map.obstacles_resulting_in_loop()
+    Ok(map.obstacles_resulting_in_loop())
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_06/input.txt");
-
-    count_guard_steps(&input)
+pub fn solve_part_1(input: &str) -> Result<usize, Error> {
+    count_guard_steps(input)
 }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_06/input.txt");
-
-    // TODO: optimise
-    count_obstacles_for_loop(&input)
+pub fn solve_part_2(input: &str) -> Result<usize, Error> {
+    count_obstacles_for_loop(input)
 }
 
 #[cfg(test)]
@@ -39,7 +33,7 @@ mod tests {
     fn counts_guard_steps() {
         let input = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
 
-        let result = count_guard_steps(input);
+        let result = count_guard_steps(input).unwrap();
 
         assert_eq!(result, 41);
     }
@@ -47,7 +41,7 @@ mod tests {
     #[test]
     fn counts_obstacles_for_loop() {
         let input = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
-        let result = count_obstacles_for_loop(input);
+        let result = count_obstacles_for_loop(input).unwrap();
 
         assert_eq!(result, 6);
     }
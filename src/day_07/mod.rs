@@ -1,6 +1,6 @@
 mod test_equation;
 
-use crate::util::read_input;
+use crate::util::{parse_lines, Error};
 use test_equation::{Operator, TestEquation};
 
 fn part_1_operations() -> Vec<Operator> {
@@ -15,33 +15,29 @@ fn part_2_operations() -> Vec<Operator> {
     ]))
 }
 
-fn sum_possible_equations(input: &str, available_operators: &Vec<Operator>) -> u64 {
-    input
-        .lines()
-        .filter_map(|line| {
-            let equation = TestEquation::from_string(line);
-
-            match equation.is_valid_with_operators(None, available_operators) {
-                true => Some(equation.result),
-                false => None,
-            }
-        })
-        .sum()
+fn sum_possible_equations(input: &str, available_operators: &Vec<Operator>) -> Result<u64, Error> {
+    let equations = parse_lines(input, TestEquation::from_string)?;
+
+    Ok(equations
+        .iter()
+        .map(
+            |equation| match equation.is_valid_with_operators(None, available_operators) {
+                true => equation.result,
+                false => 0,
+            },
+        )
+        .sum())
 }
 
-pub fn solve_part_1() -> u64 {
-    let input = read_input("src/day_07/input.txt");
-
+pub fn solve_part_1(input: &str) -> Result<u64, Error> {
     let available = part_1_operations();
-    sum_possible_equations(&input, &available)
+    sum_possible_equations(input, &available)
 }
 
-pub fn solve_part_2() -> u64 {
-    let input = read_input("src/day_07/input.txt");
-
+pub fn solve_part_2(input: &str) -> Result<u64, Error> {
     // TODO: optimise
     let available = part_2_operations();
-    sum_possible_equations(&input, &available)
+    sum_possible_equations(input, &available)
 }
 
 #[cfg(test)]
@@ -53,7 +49,7 @@ mod tests {
         let input = "190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20";
 
         let available = part_1_operations();
-        let result = sum_possible_equations(input, &available);
+        let result = sum_possible_equations(input, &available).unwrap();
 
         assert_eq!(result, 3749);
     }
@@ -63,7 +59,7 @@ mod tests {
         let input = "190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20";
 
         let available = part_2_operations();
-        let result = sum_possible_equations(input, &available);
+        let result = sum_possible_equations(input, &available).unwrap();
 
         assert_eq!(result, 11387);
     }
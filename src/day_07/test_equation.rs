@@ -1,3 +1,5 @@
+use crate::util::{parse_number, Error};
+
 #[derive(Clone, Copy)]
 pub enum Operator {
     Add,
@@ -11,22 +13,23 @@ pub struct TestEquation {
 }
 
 impl TestEquation {
-    pub fn from_string(input: &str) -> Self {
-        let components: Vec<&str> = input.split(":").collect();
-
-        let result = components.iter().nth(0).unwrap().parse().unwrap();
-        let entries = components
-            .iter()
-            .nth(1)
-            .unwrap()
+    pub fn from_string(input: &str) -> Result<Self, Error> {
+        let (result, entries) = input
+            .split_once(":")
+            .ok_or(Error::parse(input, "<result>: <numbers>"))?;
+
+        let result = parse_number(result)?;
+        let entries: Vec<u64> = entries
             .split(" ")
-            .filter_map(|entry| match entry.is_empty() {
-                true => None,
-                false => Some(entry.parse().unwrap()),
-            })
-            .collect();
+            .filter(|entry| !entry.is_empty())
+            .map(parse_number)
+            .collect::<Result<_, _>>()?;
+
+        if entries.is_empty() {
+            return Err(Error::parse(input, "at least one number after ':'"));
+        }
 
-        Self { result, entries }
+        Ok(Self { result, entries })
     }
 }
 
@@ -99,7 +102,7 @@ mod tests {
     fn verifies_equation_validity() {
         let input = "190: 10 19";
 
-        let equation = TestEquation::from_string(input);
+        let equation = TestEquation::from_string(input).unwrap();
         assert_eq!(equation.result, 190);
         assert_eq!(equation.entries, Vec::from([10, 19]));
 
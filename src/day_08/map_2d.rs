@@ -1,54 +1,35 @@
-use crate::util::Position;
+use crate::util::{Error, Grid, Position};
 use std::collections::{HashMap, HashSet};
 
 #[derive(Clone)]
 pub struct Map2D {
     nodes: HashMap<char, Vec<Position>>,
-    size: (isize, isize),
+    tiles: Grid<Option<char>>,
 }
 
 impl Map2D {
-    pub fn from_string(text: &str) -> Self {
-        let mut nodes: HashMap<char, Vec<Position>> = HashMap::new();
-
-        text.lines().enumerate().for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, character)| {
-                let x = x as isize;
-                let y = y as isize;
-
-                if character == '.' {
-                    return;
-                }
-
-                let position = (x, y);
-
-                let list = nodes.get_mut(&character);
-                let list = match list {
-                    None => Vec::from([position]),
-                    Some(list) => {
-                        list.push(position);
-                        list.to_vec()
-                    }
-                };
+    pub fn from_string(text: &str) -> Result<Self, Error> {
+        let tiles = Grid::from_string(text, "'.' or an antenna", |character| match character {
+            '.' => Some(None),
+            _ if character.is_ascii_alphanumeric() => Some(Some(character)),
+            _ => None,
+        })?;
 
-                nodes.insert(character, list);
-            });
-        });
-
-        let size_y = text.lines().count();
-        let size_x = text.lines().last().unwrap().chars().count();
+        let mut nodes: HashMap<char, Vec<Position>> = HashMap::new();
 
-        Self {
-            nodes,
-            size: (size_x as isize, size_y as isize),
+        for (position, antenna) in tiles.iter() {
+            if let Some(antenna) = antenna {
+                nodes.entry(*antenna).or_default().push(position);
+            }
         }
+
+        Ok(Self { nodes, tiles })
     }
 }
 
 impl Map2D {
     fn is_within_map(&self, position: &Position) -> bool {
-        let size = self.size;
-        position.0 >= 0 && position.1 >= 0 && position.0 < size.0 && position.1 < size.1
+        self.tiles.contains(position)
     }
 
     fn antinodes_from_positions(&self, a: &Position, b: &Position) -> Vec<Position> {
@@ -1,30 +1,26 @@
 mod map_2d;
 
-use crate::util::read_input;
+use crate::util::Error;
 use map_2d::Map2D;
 
-fn count_antinodes(input: &str) -> usize {
-    let map = Map2D::from_string(input);
+fn count_antinodes(input: &str) -> Result<usize, Error> {
+    let map = Map2D::from_string(input)?;
 
-    map.find_antinodes(false).len()
+    Ok(map.find_antinodes(false).len())
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_08/input.txt");
-
-    count_antinodes(&input)
+pub fn solve_part_1(input: &str) -> Result<usize, Error> {
+    count_antinodes(input)
 }
 
-fn count_antinodes_with_resonance(input: &str) -> usize {
-    let map = Map2D::from_string(input);
+fn count_antinodes_with_resonance(input: &str) -> Result<usize, Error> {
+    let map = Map2D::from_string(input)?;
 
-    map.find_antinodes(true).len()
+    Ok(map.find_antinodes(true).len())
 }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_08/input.txt");
-
-    count_antinodes_with_resonance(&input)
+pub fn solve_part_2(input: &str) -> Result<usize, Error> {
+    count_antinodes_with_resonance(input)
 }
 
 #[cfg(test)]
@@ -35,23 +31,34 @@ mod tests {
     fn counts_antinodes_in_map() {
         let input = "..........\n..........\n..........\n....a.....\n........a.\n.....a....\n..........\n..........\n..........\n..........";
 
-        let result = count_antinodes(input);
+        let result = count_antinodes(input).unwrap();
         assert_eq!(result, 4);
     }
 
     #[test]
     fn counts_antinodes_in_complex_map() {
-        let input = ".............\n........0...\n.....0......\n.......0....\n....0.......\n......A.....\n............\n............\n........A...\n.........A..\n............\n............";
+        let input = "............\n........0...\n.....0......\n.......0....\n....0.......\n......A.....\n............\n............\n........A...\n.........A..\n............\n............";
 
-        let result = count_antinodes(input);
+        let result = count_antinodes(input).unwrap();
         assert_eq!(result, 14);
     }
 
+    #[test]
+    fn rejects_rows_of_uneven_width() {
+        let input = ".............\n........0...\n.....0......";
+
+        let error = count_antinodes(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected a row of 13 tiles, found '........0...'"
+        );
+    }
+
     #[test]
     fn counts_antinodes_with_resonance() {
         let input = "T.........\n...T......\n.T........\n..........\n..........\n..........\n..........\n..........\n..........\n..........";
 
-        let result = count_antinodes_with_resonance(input);
+        let result = count_antinodes_with_resonance(input).unwrap();
         assert_eq!(result, 9);
     }
 }
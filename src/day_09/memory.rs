@@ -1,3 +1,4 @@
+use crate::util::Error;
 use std::collections::HashMap;
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -52,15 +53,18 @@ impl Memory {
         keys
     }
 
-    pub fn from_string(input: &str) -> Self {
+    pub fn from_string(input: &str) -> Result<Self, Error> {
         let mut memory = Memory::default();
 
         let mut block_type = MemorySlotType::Data;
         let mut index: usize = 0;
         let mut id: usize = 0;
 
-        input.chars().for_each(|entry: char| {
-            let length = entry.to_digit(10).unwrap() as usize;
+        for entry in input.trim_end().chars() {
+            let length = entry
+                .to_digit(10)
+                .ok_or(Error::parse(&entry.to_string(), "a digit"))?
+                as usize;
 
             let indices: Vec<usize> = (index..(index + length)).collect();
 
@@ -80,9 +84,9 @@ impl Memory {
 
             index += length;
             block_type = block_type.reverse();
-        });
+        }
 
-        memory
+        Ok(memory)
     }
 }
 
@@ -174,7 +178,7 @@ mod tests {
     #[test]
     fn creates_memory() {
         let input = "12345";
-        let memory = Memory::from_string(input);
+        let memory = Memory::from_string(input).unwrap();
 
         assert_eq!(
             memory.empty,
@@ -206,7 +210,7 @@ mod tests {
     #[test]
     fn sorts_memory() {
         let input = "12345";
-        let mut memory = Memory::from_string(input);
+        let mut memory = Memory::from_string(input).unwrap();
         memory.order(false);
 
         assert_eq!(
@@ -218,7 +222,7 @@ mod tests {
     #[test]
     fn sorts_memory_complex_case() {
         let input = "2333133121414131402";
-        let mut memory = Memory::from_string(input);
+        let mut memory = Memory::from_string(input).unwrap();
 
         memory.order(false);
 
@@ -230,12 +234,12 @@ mod tests {
 
     #[test]
     fn finds_continuous_space_with_length() {
-        let memory = Memory::from_string("2333133121414131402");
+        let memory = Memory::from_string("2333133121414131402").unwrap();
 
         let result = memory.find_empty_indices(3, true);
         assert_eq!(result, Some(Vec::from([2, 3, 4])));
 
-        let memory = Memory::from_string("12345");
+        let memory = Memory::from_string("12345").unwrap();
 
         let result = memory.find_empty_indices(3, true);
         assert_eq!(result, Some(Vec::from([6, 7, 8])));
@@ -250,7 +254,7 @@ mod tests {
     #[test]
     fn sorts_memory_by_whole_files() {
         let input = "2333133121414131402";
-        let mut memory = Memory::from_string(input);
+        let mut memory = Memory::from_string(input).unwrap();
 
         memory.order(true);
 
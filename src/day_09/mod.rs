@@ -1,34 +1,30 @@
 mod memory;
 
-use crate::util::read_input;
+use crate::util::Error;
 use memory::Memory;
 
-fn order_memory_checksum(input: &str) -> usize {
-    let mut memory = Memory::from_string(input);
+fn order_memory_checksum(input: &str) -> Result<usize, Error> {
+    let mut memory = Memory::from_string(input)?;
 
     memory.order(false);
-    memory.checksum()
+    Ok(memory.checksum())
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_09/input.txt");
-
+pub fn solve_part_1(input: &str) -> Result<usize, Error> {
     // TODO: optimise
-    order_memory_checksum(&input)
+    order_memory_checksum(input)
 }
 
-fn whole_file_order_memory_checksum(input: &str) -> usize {
-    let mut memory = Memory::from_string(input);
+fn whole_file_order_memory_checksum(input: &str) -> Result<usize, Error> {
+    let mut memory = Memory::from_string(input)?;
 
     memory.order(true);
-    memory.checksum()
+    Ok(memory.checksum())
 }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_09/input.txt");
-
+pub fn solve_part_2(input: &str) -> Result<usize, Error> {
     // TODO: optimise
-    whole_file_order_memory_checksum(&input)
+    whole_file_order_memory_checksum(input)
 }
 
 #[cfg(test)]
@@ -38,7 +34,7 @@ mod tests {
     #[test]
     fn finds_ordered_memory_checksum() {
         let input = "2333133121414131402";
-        let result = order_memory_checksum(input);
+        let result = order_memory_checksum(input).unwrap();
 
         assert_eq!(result, 1928);
     }
@@ -46,8 +42,16 @@ mod tests {
     #[test]
     fn finds_whole_file_ordered_memory_checksum() {
         let input = "2333133121414131402";
-        let result = whole_file_order_memory_checksum(input);
+        let result = whole_file_order_memory_checksum(input).unwrap();
 
         assert_eq!(result, 2858);
     }
+
+    #[test]
+    fn accepts_trailing_newline() {
+        let input = "2333133121414131402\n";
+
+        assert_eq!(order_memory_checksum(input).unwrap(), 1928);
+        assert_eq!(whole_file_order_memory_checksum(input).unwrap(), 2858);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::util::Position;
+use crate::util::{Error, Position};
 use std::collections::{HashMap, HashSet};
 
 pub struct HeightMap {
@@ -7,12 +7,12 @@ pub struct HeightMap {
 }
 
 impl HeightMap {
-    pub fn from_string(s: &str) -> Self {
+    pub fn from_string(s: &str) -> Result<Self, Error> {
         let mut heights = HashMap::new();
         let mut zero_elevations = Vec::new();
 
-        s.lines().enumerate().for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, character)| {
+        for (y, line) in s.lines().enumerate() {
+            for (x, character) in line.chars().enumerate() {
                 let height = character.to_digit(10);
 
                 match height {
@@ -25,15 +25,20 @@ impl HeightMap {
 
                         heights.insert(position, height as usize);
                     }
-                    _ => {}
+                    None if character == '.' => {}
+                    None => {
+                        return Err(
+                            Error::parse(&character.to_string(), "a height or '.'").at_line(y + 1)
+                        )
+                    }
                 }
-            })
-        });
+            }
+        }
 
-        Self {
+        Ok(Self {
             heights,
             zero_elevations,
-        }
+        })
     }
 }
 
@@ -130,7 +135,7 @@ mod tests {
     #[test]
     fn finds_trails_from_position() {
         let input = "0123\n1114\n2345\n9876";
-        let map = HeightMap::from_string(input);
+        let map = HeightMap::from_string(input).unwrap();
 
         let result = map.trails_from_position(&(0, 0));
         assert_eq!(result.len(), 2);
@@ -139,7 +144,7 @@ mod tests {
     #[test]
     fn finds_trails_from_position_other_map() {
         let input = "10..9..\n2...8..\n3...7..\n4567654\n...8..3\n...9..2\n.....01";
-        let map = HeightMap::from_string(input);
+        let map = HeightMap::from_string(input).unwrap();
 
         let result = map.trails_from_position(&(1, 0));
         assert_eq!(result.len(), 1);
@@ -1,33 +1,28 @@
 mod height_map;
 
+use crate::util::Error;
 use height_map::HeightMap;
 
-use crate::util::read_input;
-
-fn find_trailhead_score(input: &str) -> usize {
-    let map = HeightMap::from_string(input);
+fn find_trailhead_score(input: &str) -> Result<usize, Error> {
+    let map = HeightMap::from_string(input)?;
     let trails = map.find_trailhead_scores();
 
-    trails.values().sum()
+    Ok(trails.values().sum())
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_10/input.txt");
-
-    find_trailhead_score(&input)
+pub fn solve_part_1(input: &str) -> Result<usize, Error> {
+    find_trailhead_score(input)
 }
 
-fn find_distinct_trails(input: &str) -> usize {
-    let map = HeightMap::from_string(input);
+fn find_distinct_trails(input: &str) -> Result<usize, Error> {
+    let map = HeightMap::from_string(input)?;
     let trails = map.find_trailhead_trails();
 
-    trails.values().sum()
+    Ok(trails.values().sum())
 }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_10/input.txt");
-
-    find_distinct_trails(&input)
+pub fn solve_part_2(input: &str) -> Result<usize, Error> {
+    find_distinct_trails(input)
 }
 
 #[cfg(test)]
@@ -38,7 +33,7 @@ mod tests {
     fn finds_trailheads() {
         let input =
             "89010123\n78121874\n87430965\n96549874\n45678903\n32019012\n01329801\n10456732";
-        let result = find_trailhead_score(input);
+        let result = find_trailhead_score(input).unwrap();
 
         assert_eq!(result, 36)
     }
@@ -47,7 +42,7 @@ mod tests {
     fn finds_distinct_trails() {
         let input =
             "89010123\n78121874\n87430965\n96549874\n45678903\n32019012\n01329801\n10456732";
-        let result = find_distinct_trails(input);
+        let result = find_distinct_trails(input).unwrap();
 
         assert_eq!(result, 81)
     }
@@ -1,11 +1,11 @@
 use super::number::Number;
-use crate::util::divide_integer;
+use crate::util::{divide_integer, parse_number, Error};
 
 impl Number {
-    pub fn from_string(input: &str) -> Self {
-        let value = input.parse().unwrap();
+    pub fn from_string(input: &str) -> Result<Self, Error> {
+        let value = parse_number(input)?;
 
-        Self { value }
+        Ok(Self { value })
     }
 
     pub fn new(value: u64) -> Self {
@@ -57,14 +57,14 @@ pub struct NumberCollection {
 }
 
 impl NumberCollection {
-    pub fn from_string(input: &str) -> Self {
+    pub fn from_string(input: &str) -> Result<Self, Error> {
         let numbers = input
+            .trim_end()
             .split(" ")
-            .into_iter()
-            .map(|digits| Number::from_string(digits))
-            .collect();
+            .map(Number::from_string)
+            .collect::<Result<_, _>>()?;
 
-        Self { numbers }
+        Ok(Self { numbers })
     }
 }
 
@@ -96,7 +96,7 @@ mod tests {
     fn determines_arrangement_after_1_blink() {
         let input = "0 1 10 99 999";
 
-        let mut collection = NumberCollection::from_string(input);
+        let mut collection = NumberCollection::from_string(input).unwrap();
         collection.blink();
 
         assert_eq!(collection.to_numbers(), vec![1, 2024, 1, 0, 9, 9, 2021976]);
@@ -1,4 +1,5 @@
 use super::number::Number;
+use crate::util::{parse_number, Error};
 use std::collections::HashMap;
 
 #[derive(Debug, Default)]
@@ -7,11 +8,11 @@ pub struct NumberMap {
 }
 
 impl NumberMap {
-    pub fn from_string(input: &str) -> Self {
+    pub fn from_string(input: &str) -> Result<Self, Error> {
         let mut numbers = HashMap::new();
 
-        for value_string in input.split(" ") {
-            let value: u64 = value_string.parse().unwrap();
+        for value_string in input.trim_end().split(" ") {
+            let value: u64 = parse_number(value_string)?;
 
             match numbers.get(&value) {
                 Some(count) => numbers.insert(value, count + 1),
@@ -19,7 +20,7 @@ impl NumberMap {
             };
         }
 
-        Self { numbers }
+        Ok(Self { numbers })
     }
 }
 
@@ -77,7 +78,7 @@ mod tests {
     fn determines_arrangement_after_1_blink() {
         let input = "125 17";
 
-        let mut collection = NumberMap::from_string(input);
+        let mut collection = NumberMap::from_string(input).unwrap();
         collection.blink_times(1);
 
         assert_eq!(
@@ -90,7 +91,7 @@ mod tests {
     fn determines_arrangement_after_2_blinks() {
         let input = "125 17";
 
-        let mut collection = NumberMap::from_string(input);
+        let mut collection = NumberMap::from_string(input).unwrap();
         collection.blink_times(2);
 
         assert_eq!(
@@ -103,7 +104,7 @@ mod tests {
     fn determines_arrangement_after_3_blinks() {
         let input = "125 17";
 
-        let mut collection = NumberMap::from_string(input);
+        let mut collection = NumberMap::from_string(input).unwrap();
         collection.blink_times(3);
 
         assert_eq!(
@@ -116,7 +117,7 @@ mod tests {
     fn determines_arrangement_after_4_blinks() {
         let input = "125 17";
 
-        let mut collection = NumberMap::from_string(input);
+        let mut collection = NumberMap::from_string(input).unwrap();
         collection.blink_times(4);
 
         assert_eq!(
@@ -138,7 +139,7 @@ mod tests {
     fn determines_arrangement_after_5_blinks() {
         let input = "125 17";
 
-        let mut collection = NumberMap::from_string(input);
+        let mut collection = NumberMap::from_string(input).unwrap();
         collection.blink_times(5);
 
         assert_eq!(
@@ -164,7 +165,7 @@ mod tests {
     fn determines_arrangement_after_6_blinks() {
         let input = "125 17";
 
-        let mut collection = NumberMap::from_string(input);
+        let mut collection = NumberMap::from_string(input).unwrap();
         collection.blink_times(6);
 
         assert_eq!(
@@ -196,7 +197,7 @@ mod tests {
     fn determines_total_after_25_blinks() {
         let input = "125 17";
 
-        let mut collection = NumberMap::from_string(input);
+        let mut collection = NumberMap::from_string(input).unwrap();
         collection.blink_times(25);
 
         assert_eq!(collection.count(), 55312);
@@ -2,46 +2,51 @@ mod collection;
 mod map;
 mod number;
 
-use crate::util::read_input;
+use crate::util::Error;
 use collection::NumberCollection;
 use map::NumberMap;
 
-pub fn arrangement_after_blinks(input: &str, blinks: u64) -> usize {
-    let mut collection = NumberCollection::from_string(input);
+pub fn arrangement_after_blinks(input: &str, blinks: u64) -> Result<usize, Error> {
+    let mut collection = NumberCollection::from_string(input)?;
 
     for _ in 0..blinks {
         collection.blink();
     }
 
-    collection.count()
+    Ok(collection.count())
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_11/input.txt");
-
-    arrangement_after_blinks(&input, 25)
+pub fn solve_part_1(input: &str) -> Result<usize, Error> {
+    arrangement_after_blinks(input, 25)
 }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_11/input.txt");
-
-    let mut collection = NumberMap::from_string(&input);
+pub fn solve_part_2(input: &str) -> Result<usize, Error> {
+    let mut collection = NumberMap::from_string(input)?;
 
     collection.blink_times(75);
-    collection.count()
+    Ok(collection.count())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::read_input;
 
     #[test]
     fn solves_part_1() {
-        let input = read_input("src/day_11/input.txt");
+        let input = read_input("src/day_11/input.txt").unwrap();
 
-        let mut collection = NumberMap::from_string(&input);
+        let mut collection = NumberMap::from_string(&input).unwrap();
 
         collection.blink_times(25);
         assert_eq!(collection.count(), 183620);
     }
+
+    #[test]
+    fn accepts_trailing_newline() {
+        let input = "125 17\n";
+
+        assert_eq!(arrangement_after_blinks(input, 25).unwrap(), 55312);
+        assert_eq!(solve_part_2(input).unwrap(), 65601038650482);
+    }
 }
@@ -1,29 +1,23 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
-use crate::util::{move_steps_in_direction, Direction, Position};
+use crate::util::{move_steps_in_direction, Direction, Error, Grid, Position};
 
 use super::area::Area;
 
 pub struct Map2D {
-    tiles: HashMap<Position, char>,
+    tiles: Grid<char>,
 }
 
 impl Map2D {
-    pub fn from_string(text: &str) -> Self {
-        let mut tiles: HashMap<Position, char> = HashMap::new();
-
-        text.lines().enumerate().for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, character)| {
-                let x = x as isize;
-                let y = y as isize;
-
-                let position = (x, y);
-
-                tiles.insert(position, character);
-            });
-        });
+    pub fn from_string(text: &str) -> Result<Self, Error> {
+        let tiles = Grid::from_string(text, "a plant type", |character| {
+            match character.is_ascii_uppercase() {
+                true => Some(character),
+                false => None,
+            }
+        })?;
 
-        Self { tiles }
+        Ok(Self { tiles })
     }
 }
 
@@ -33,11 +27,11 @@ impl Map2D {
         let mut result: Vec<Area> = vec![];
 
         for (position, character) in self.tiles.iter() {
-            if used.contains(position) {
+            if used.contains(&position) {
                 continue;
             }
 
-            let area = self.find_area_from(position, character);
+            let area = self.find_area_from(&position, character);
 
             used.extend(area.tiles.clone());
             result.push(area);
@@ -92,7 +86,9 @@ mod tests {
     fn finds_specific_area_fence_cost() {
         let input = "AAAA\nBBCD\nBBCC\nEEEC";
 
-        let area = Map2D::from_string(input).find_area_from(&(2, 2), &'C');
+        let area = Map2D::from_string(input)
+            .unwrap()
+            .find_area_from(&(2, 2), &'C');
 
         assert_eq!(area.tiles.len(), 4);
         assert_eq!(area.perimeter_tiles.len(), 8);
@@ -103,7 +99,9 @@ mod tests {
     fn finds_specific_area_fence_cost_with_discount() {
         let input = "AAAA\nBBCD\nBBCC\nEEEC";
 
-        let area = Map2D::from_string(input).find_area_from(&(2, 2), &'C');
+        let area = Map2D::from_string(input)
+            .unwrap()
+            .find_area_from(&(2, 2), &'C');
 
         assert_eq!(area.tiles.len(), 4);
         assert_eq!(area.number_of_sides(), 8);
@@ -114,7 +112,7 @@ mod tests {
     fn find_areas() {
         let input = "AAAA\nBBCD\nBBCC\nEEEC";
 
-        let areas = Map2D::from_string(input).find_areas();
+        let areas = Map2D::from_string(input).unwrap().find_areas();
 
         let area_a = areas.iter().find(|area| area.character == 'A').unwrap();
         assert_eq!(area_a.tiles.len(), 4);
@@ -2,6 +2,7 @@ use super::{
     linear_algebra::solve_2n_order_system,
     model::{text_to_button, text_to_prize, Coordinate},
 };
+use crate::util::{expect_component, Error};
 
 pub struct ClawMachine {
     button_a: Coordinate,
@@ -10,14 +11,24 @@ pub struct ClawMachine {
 }
 
 impl ClawMachine {
-    pub fn from_string(input: &str) -> Self {
-        let lines: Vec<&str> = input.lines().collect();
-
-        Self {
-            button_a: text_to_button(lines.get(0).unwrap()),
-            button_b: text_to_button(lines.get(1).unwrap()),
-            prize: text_to_prize(lines.get(2).unwrap()),
-        }
+    pub fn from_string(input: &str) -> Result<Self, Error> {
+        let mut lines = input.lines();
+
+        let button_a = expect_component(lines.next(), input, "button A")
+            .and_then(text_to_button)
+            .map_err(|error| error.at_line(1))?;
+        let button_b = expect_component(lines.next(), input, "button B")
+            .and_then(text_to_button)
+            .map_err(|error| error.at_line(2))?;
+        let prize = expect_component(lines.next(), input, "a prize")
+            .and_then(text_to_prize)
+            .map_err(|error| error.at_line(3))?;
+
+        Ok(Self {
+            button_a,
+            button_b,
+            prize,
+        })
     }
 }
 
@@ -48,7 +59,7 @@ mod tests {
     #[test]
     fn determines_lowest_token_price() {
         let input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400";
-        let machine = ClawMachine::from_string(input);
+        let machine = ClawMachine::from_string(input).unwrap();
 
         assert_eq!(machine.button_a, (94, 34));
         assert_eq!(machine.button_b, (22, 67));
@@ -56,12 +67,12 @@ mod tests {
         assert_eq!(machine.lowest_token_price(), 280);
 
         let input = "Button A: X+26, Y+66\nButton B: X+67, Y+21\nPrize: X=12748, Y=12176";
-        let machine = ClawMachine::from_string(input);
+        let machine = ClawMachine::from_string(input).unwrap();
 
         assert_eq!(machine.lowest_token_price(), 0);
 
         let input = "Button A: X+69, Y+23\nButton B: X+27, Y+71\nPrize: X=18641, Y=10279";
-        let machine = ClawMachine::from_string(input);
+        let machine = ClawMachine::from_string(input).unwrap();
 
         assert_eq!(machine.lowest_token_price(), 0);
     }
@@ -2,33 +2,25 @@ mod claw_machine;
 mod linear_algebra;
 mod model;
 
-use crate::util::read_input;
+use crate::util::{parse_blocks, Error};
 use claw_machine::ClawMachine;
 
-pub fn find_lowest_token_price(input: &str, add: i64) -> i64 {
-    input
-        .split("\n\n")
-        .map(|text| {
-            let mut machine = ClawMachine::from_string(text);
-            machine.add_prize(add);
+pub fn find_lowest_token_price(input: &str, add: i64) -> Result<i64, Error> {
+    let machines = parse_blocks(input, ClawMachine::from_string)?;
 
+    Ok(machines
+        .into_iter()
+        .map(|mut machine| {
+            machine.add_prize(add);
             machine.lowest_token_price()
         })
-        .sum()
+        .sum())
 }
 
-pub fn solve_part_1() -> i64 {
-    let input = read_input("src/day_13/input.txt");
-
-    let result = find_lowest_token_price(&input, 0);
-
-    assert_eq!(result, 32026);
-
-    result
+pub fn solve_part_1(input: &str) -> Result<i64, Error> {
+    find_lowest_token_price(input, 0)
 }
 
-pub fn solve_part_2() -> i64 {
-    let input = read_input("src/day_13/input.txt");
-
-    find_lowest_token_price(&input, 10000000000000)
+pub fn solve_part_2(input: &str) -> Result<i64, Error> {
+    find_lowest_token_price(input, 10000000000000)
 }
@@ -1,25 +1,35 @@
+use crate::util::{expect_component, parse_number, Error};
+
 pub type Coordinate = (i64, i64);
 
-pub fn text_to_button(input: &str) -> Coordinate {
-    let next: String = input.chars().skip(10).collect();
-    let entries: Vec<&str> = next.split(", ").collect();
+fn text_to_pair(input: &str, text: &str, expected: &str) -> Result<Coordinate, Error> {
+    let mut entries = text.split(", ");
+
+    Ok((
+        text_to_coordinate(expect_component(entries.next(), input, expected)?)?,
+        text_to_coordinate(expect_component(entries.next(), input, expected)?)?,
+    ))
+}
+
+pub fn text_to_button(input: &str) -> Result<Coordinate, Error> {
+    let expected = "Button <name>: X+<n>, Y+<n>";
+    let next = input
+        .strip_prefix("Button ")
+        .and_then(|next| next.split_once(": "))
+        .ok_or(Error::parse(input, expected))?;
 
-    (
-        text_to_coordinate(&entries.get(0).unwrap()),
-        text_to_coordinate(&entries.get(1).unwrap()),
-    )
+    text_to_pair(input, next.1, expected)
 }
 
-pub fn text_to_coordinate(input: &str) -> i64 {
-    input.chars().skip(2).collect::<String>().parse().unwrap()
+pub fn text_to_coordinate(input: &str) -> Result<i64, Error> {
+    parse_number(&input.chars().skip(2).collect::<String>())
 }
 
-pub fn text_to_prize(input: &str) -> Coordinate {
-    let next: String = input.chars().skip(7).collect();
-    let entries: Vec<&str> = next.split(", ").collect();
+pub fn text_to_prize(input: &str) -> Result<Coordinate, Error> {
+    let expected = "Prize: X=<n>, Y=<n>";
+    let next = input
+        .strip_prefix("Prize: ")
+        .ok_or(Error::parse(input, expected))?;
 
-    (
-        text_to_coordinate(&entries.get(0).unwrap()),
-        text_to_coordinate(&entries.get(1).unwrap()),
-    )
+    text_to_pair(input, next, expected)
 }
@@ -1,28 +1,29 @@
 mod robot;
 
-use crate::util::{read_input, Position};
+use crate::util::{parse_lines, Error, Position};
 use robot::Robot;
 use std::collections::HashSet;
 
 fn print_map(space: (isize, isize), robot_positions: HashSet<Position>) {
-    println!("");
+    eprintln!();
 
     for y in 0..=space.1 {
-        println!("");
         for x in 0..=space.0 {
             match robot_positions.contains(&(x, y)) {
-                true => print!("#"),
-                false => print!("."),
+                true => eprint!("#"),
+                false => eprint!("."),
             }
         }
+        eprintln!();
     }
 }
 
-fn find_safety_score(input: &str, space: (isize, isize), times: usize) -> usize {
-    let quadrant_counts = input
-        .lines()
-        .map(|text| {
-            let mut robot = Robot::from_string(text);
+fn find_safety_score(input: &str, space: (isize, isize), times: usize) -> Result<usize, Error> {
+    let robots = parse_lines(input, Robot::from_string)?;
+
+    let quadrant_counts = robots
+        .into_iter()
+        .map(|mut robot| {
             robot.move_times(space, times);
 
             robot.find_quadrant(space)
@@ -40,13 +41,11 @@ fn find_safety_score(input: &str, space: (isize, isize), times: usize) -> usize
             }
         });
 
-    quadrant_counts.0 * quadrant_counts.1 * quadrant_counts.2 * quadrant_counts.3
+    Ok(quadrant_counts.0 * quadrant_counts.1 * quadrant_counts.2 * quadrant_counts.3)
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_14/input.txt");
-
-    find_safety_score(&input, (101, 103), 100)
+pub fn solve_part_1(input: &str) -> Result<usize, Error> {
+    find_safety_score(input, (101, 103), 100)
 }
 
 fn are_positions_lined_up(positions: &HashSet<Position>, line_length: usize) -> bool {
@@ -69,8 +68,8 @@ fn are_positions_lined_up(positions: &HashSet<Position>, line_length: usize) ->
     false
 }
 
-fn find_moves_for_easter_egg(input: &str, space: (isize, isize)) -> usize {
-    let mut robots: Vec<Robot> = input.lines().map(|text| Robot::from_string(text)).collect();
+fn find_moves_for_easter_egg(input: &str, space: (isize, isize)) -> Result<usize, Error> {
+    let mut robots = parse_lines(input, Robot::from_string)?;
 
     let mut robot_references: Vec<&mut Robot> = robots.iter_mut().collect();
     let mut times = 0;
@@ -93,13 +92,11 @@ fn find_moves_for_easter_egg(input: &str, space: (isize, isize)) -> usize {
         }
     }
 
-    times
+    Ok(times)
 }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_14/input.txt");
-
-    find_moves_for_easter_egg(&input, (101, 103))
+pub fn solve_part_2(input: &str) -> Result<usize, Error> {
+    find_moves_for_easter_egg(input, (101, 103))
 }
 
 #[cfg(test)]
@@ -110,7 +107,7 @@ mod tests {
     fn calculates_safety_score() {
         let input = "p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,3 v=-1,2\np=2,0 v=2,-1\np=0,0 v=1,3\np=3,0 v=-2,-2\np=7,6 v=-1,-3\np=3,0 v=-1,-2\np=9,3 v=2,3\np=7,3 v=-1,2\np=2,4 v=2,-3\np=9,5 v=-3,-3";
 
-        let result = find_safety_score(input, (11, 7), 100);
+        let result = find_safety_score(input, (11, 7), 100).unwrap();
         assert_eq!(result, 12);
     }
 }
@@ -1,28 +1,31 @@
-use crate::util::Position;
+use crate::util::{expect_component, parse_number, Error, Position};
 
 pub struct Robot {
     pub position: Position,
     velocity: Position,
 }
 
-fn coordinates_from_string(input: &str) -> Position {
-    let mut coordinates = input.split("=").nth(1).unwrap().split(",");
-    (
-        coordinates.nth(0).unwrap().parse().unwrap(),
-        coordinates.nth(0).unwrap().parse().unwrap(),
-    )
+fn coordinates_from_string(input: &str) -> Result<Position, Error> {
+    let (_, coordinates) = input
+        .split_once("=")
+        .ok_or(Error::parse(input, "<name>=<x>,<y>"))?;
+    let (x, y) = coordinates
+        .split_once(",")
+        .ok_or(Error::parse(input, "<name>=<x>,<y>"))?;
+
+    Ok((parse_number(x)?, parse_number(y)?))
 }
 
 impl Robot {
-    pub fn from_string(input: &str) -> Self {
+    pub fn from_string(input: &str) -> Result<Self, Error> {
         let mut components = input.split(" ");
-        let position_string = components.nth(0).unwrap();
-        let velocity_string = components.nth(0).unwrap();
+        let position_string = expect_component(components.next(), input, "a position")?;
+        let velocity_string = expect_component(components.next(), input, "a velocity")?;
 
-        Self {
-            position: coordinates_from_string(position_string),
-            velocity: coordinates_from_string(velocity_string),
-        }
+        Ok(Self {
+            position: coordinates_from_string(position_string)?,
+            velocity: coordinates_from_string(velocity_string)?,
+        })
     }
 }
 
@@ -80,7 +83,7 @@ mod tests {
         let input = "p=2,4 v=2,-3";
         let space = (11, 7);
 
-        let mut robot = Robot::from_string(input);
+        let mut robot = Robot::from_string(input).unwrap();
         assert_eq!(robot.position, (2, 4));
         assert_eq!(robot.velocity, (2, -3));
 
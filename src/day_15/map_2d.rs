@@ -1,7 +1,7 @@
-use crate::util::{move_steps_in_direction, Direction, Position};
-use std::collections::HashMap;
+use crate::util::{move_steps_in_direction, Direction, Error, Grid, Position};
+use std::fmt::Display;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MapFeature {
     Box,
     BoxLeft,
@@ -10,38 +10,66 @@ pub enum MapFeature {
     Wall,
 }
 
+impl From<MapFeature> for char {
+    fn from(value: MapFeature) -> Self {
+        match value {
+            MapFeature::Box => 'O',
+            MapFeature::BoxLeft => '[',
+            MapFeature::BoxRight => ']',
+            MapFeature::Empty => '.',
+            MapFeature::Wall => '#',
+        }
+    }
+}
+
 pub struct Map2D {
-    tiles: HashMap<Position, MapFeature>,
+    tiles: Grid<MapFeature>,
     pub start: Position,
 }
 
 impl Map2D {
-    pub fn from_string(text: &str) -> Self {
-        let mut tiles = HashMap::new();
-        let mut start = (0, 0);
-
-        text.lines().enumerate().for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, character)| {
-                let x = x as isize;
-                let y = y as isize;
-
-                let feature = match character {
-                    '#' => MapFeature::Wall,
-                    'O' => MapFeature::Box,
-                    '@' => {
-                        start = (x, y);
-                        MapFeature::Empty
-                    }
-                    '[' => MapFeature::BoxLeft,
-                    ']' => MapFeature::BoxRight,
-                    _ => MapFeature::Empty,
-                };
-
-                tiles.insert((x, y), feature);
-            });
+    pub fn from_string(text: &str) -> Result<Self, Error> {
+        let characters = Grid::from_characters(text, ".#O@[]")?;
+
+        let start = characters.find(&'@').unwrap_or_default();
+        let tiles = characters.map(|character| match character {
+            '#' => MapFeature::Wall,
+            'O' => MapFeature::Box,
+            '[' => MapFeature::BoxLeft,
+            ']' => MapFeature::BoxRight,
+            _ => MapFeature::Empty,
         });
 
-        Self { tiles, start }
+        Ok(Self { tiles, start })
+    }
+
+    pub fn widen(&self) -> Self {
+        let mut tiles = Grid::new(
+            self.tiles.width() * 2,
+            self.tiles.height(),
+            MapFeature::Empty,
+        );
+
+        for ((x, y), feature) in self.tiles.iter() {
+            let (left, right) = match feature {
+                MapFeature::Box => (MapFeature::BoxLeft, MapFeature::BoxRight),
+                _ => (*feature, *feature),
+            };
+
+            tiles.set(&(x * 2, y), left);
+            tiles.set(&(x * 2 + 1, y), right);
+        }
+
+        Self {
+            tiles,
+            start: (self.start.0 * 2, self.start.1),
+        }
+    }
+}
+
+impl Display for Map2D {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tiles)
     }
 }
 
@@ -50,11 +78,11 @@ impl Map2D {
         self.tiles.get(position)
     }
 
-    pub fn get_tiles(&self) -> &HashMap<Position, MapFeature> {
+    pub fn get_tiles(&self) -> &Grid<MapFeature> {
         &self.tiles
     }
 
-    pub fn set_tiles(&mut self, tiles: HashMap<Position, MapFeature>) {
+    pub fn set_tiles(&mut self, tiles: Grid<MapFeature>) {
         self.tiles = tiles;
     }
 
@@ -85,8 +113,8 @@ impl Map2D {
 
         match from_box {
             MapFeature::Box => {
-                self.tiles.insert(*from, MapFeature::Empty);
-                self.tiles.insert(*to, MapFeature::Box);
+                self.tiles.set(from, MapFeature::Empty);
+                self.tiles.set(to, MapFeature::Box);
             }
             MapFeature::BoxLeft | MapFeature::BoxRight => {}
             _ => {}
@@ -96,7 +124,7 @@ impl Map2D {
     pub fn sum_box_coordinates(&self) -> usize {
         let mut result = 0;
 
-        for ((x, y), tile) in &self.tiles {
+        for ((x, y), tile) in self.tiles.iter() {
             match tile {
                 MapFeature::Box | MapFeature::BoxLeft => {
                     result += ((y * 100) + x) as usize;
@@ -1,4 +1,4 @@
-use crate::util::{move_steps_in_direction, Direction, Position};
+use crate::util::{move_steps_in_direction, Direction, Error, Position};
 
 use super::map_2d::{Map2D, MapFeature};
 
@@ -8,23 +8,28 @@ pub struct Robot {
 }
 
 impl Robot {
-    pub fn from_moves(position: Position, input: &str) -> Self {
+    pub fn from_moves(position: Position, input: &str) -> Result<Self, Error> {
         let mut moves: Vec<Direction> = vec![];
 
-        input.lines().for_each(|line| {
-            line.chars().for_each(|character| {
+        for (y, line) in input.lines().enumerate() {
+            for character in line.chars() {
                 let direction = match character {
                     '<' => Direction::W,
                     '>' => Direction::E,
                     'v' => Direction::S,
-                    _ => Direction::N,
+                    '^' => Direction::N,
+                    _ => {
+                        return Err(
+                            Error::parse(&character.to_string(), "one of '<>^v'").at_line(y + 1)
+                        )
+                    }
                 };
 
                 moves.push(direction);
-            });
-        });
+            }
+        }
 
-        Self { position, moves }
+        Ok(Self { position, moves })
     }
 }
 
@@ -87,14 +92,14 @@ fn move_large_boxes_x(map: &mut Map2D, position: &Position, direction: &Directio
 
         match direction {
             Direction::E => {
-                tiles.insert(*next, MapFeature::Empty);
-                tiles.insert((next.0 + 1, next.1), MapFeature::BoxLeft);
-                tiles.insert((next.0 + 2, next.1), MapFeature::BoxRight);
+                tiles.set(next, MapFeature::Empty);
+                tiles.set(&(next.0 + 1, next.1), MapFeature::BoxLeft);
+                tiles.set(&(next.0 + 2, next.1), MapFeature::BoxRight);
             }
             _ => {
-                tiles.insert((next.0 + 1, next.1), MapFeature::Empty);
-                tiles.insert((next.0 - 1, next.1), MapFeature::BoxLeft);
-                tiles.insert(*next, MapFeature::BoxRight);
+                tiles.set(&(next.0 + 1, next.1), MapFeature::Empty);
+                tiles.set(&(next.0 - 1, next.1), MapFeature::BoxLeft);
+                tiles.set(next, MapFeature::BoxRight);
             }
         }
     }
@@ -150,8 +155,8 @@ fn move_large_boxes_y(map: &mut Map2D, position: &Position, direction: &Directio
             for (feature, box_position) in box_positions(*next) {
                 let move_to = move_steps_in_direction(&box_position, 1, direction);
 
-                tiles.insert(box_position, MapFeature::Empty);
-                tiles.insert(move_to, feature);
+                tiles.set(&box_position, MapFeature::Empty);
+                tiles.set(&move_to, feature);
             }
         }
     }
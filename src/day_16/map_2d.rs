@@ -1,6 +1,5 @@
-use super::heading::Heading;
-use crate::util::{move_steps_in_direction, Direction, Position};
-use std::collections::{BinaryHeap, HashMap};
+use crate::util::{dijkstra, move_steps_in_direction, Direction, Error, Grid, Position, Search};
+use std::collections::HashSet;
 
 #[derive(PartialEq)]
 enum MapFeature {
@@ -9,102 +8,120 @@ enum MapFeature {
 }
 
 pub struct Map2D {
-    tiles: HashMap<Position, MapFeature>,
+    tiles: Grid<MapFeature>,
     start: Position,
     end: Position,
 }
 
 impl Map2D {
-    pub fn from_string(text: &str) -> Self {
-        let mut tiles: HashMap<Position, MapFeature> = HashMap::new();
-        let mut start: Position = (0, 0);
-        let mut end: Position = (0, 0);
-
-        text.lines().enumerate().for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, character)| {
-                let x = x as isize;
-                let y = y as isize;
-
-                let position = (x, y);
-                let tile = match character {
-                    '#' => MapFeature::Wall,
-                    'S' => {
-                        start = position;
-                        MapFeature::Empty
-                    }
-                    'E' => {
-                        end = position;
-                        MapFeature::Empty
-                    }
-                    _ => MapFeature::Empty,
-                };
-
-                tiles.insert(position, tile);
-            });
+    pub fn from_string(text: &str) -> Result<Self, Error> {
+        let characters = Grid::from_characters(text, ".#SE")?;
+
+        let start = characters.find(&'S').unwrap_or_default();
+        let end = characters.find(&'E').unwrap_or_default();
+        let tiles = characters.map(|character| match character {
+            '#' => MapFeature::Wall,
+            _ => MapFeature::Empty,
         });
 
-        Self { tiles, start, end }
+        Ok(Self { tiles, start, end })
+    }
+}
+
+type Reindeer = (Position, Direction);
+
+fn step_cost(from: &Reindeer, to: &Reindeer) -> usize {
+    match from.1 == to.1 {
+        true => 1,
+        false => 1000,
     }
 }
 
 impl Map2D {
-    fn possible_steps_from(
-        &self,
-        position: &Position,
-        direction: &Direction,
-    ) -> Vec<(Position, Direction)> {
-        let directions = vec![
-            direction.rotate_90_degress_counter_clockwise(),
-            *direction,
-            direction.rotate_90_degress_clockwise(),
+    fn possible_steps_from(&self, (position, direction): &Reindeer) -> Vec<Reindeer> {
+        let mut steps = vec![
+            (*position, direction.rotate_90_degress_counter_clockwise()),
+            (*position, direction.rotate_90_degress_clockwise()),
         ];
 
-        directions
-            .iter()
-            .filter_map(|direction| {
-                let next = move_steps_in_direction(position, 1, direction);
-                match self.tiles.get(&next) {
-                    Some(tile) if tile == &MapFeature::Empty => Some((next, *direction)),
-                    _ => None,
-                }
-            })
-            .collect()
+        let next = move_steps_in_direction(position, 1, direction);
+
+        if self.tiles.get(&next) == Some(&MapFeature::Empty) {
+            steps.push((next, *direction));
+        }
+
+        steps
+    }
+
+    fn find_best_paths(&self) -> Option<Search<Reindeer>> {
+        dijkstra(
+            (self.start, Direction::E),
+            |reindeer| self.possible_steps_from(reindeer),
+            step_cost,
+            |(position, _)| position == &self.end,
+        )
     }
 
     pub fn find_best_path_cost(&self) -> Option<usize> {
-        let mut queue: BinaryHeap<Heading> = BinaryHeap::from([(self.start, Direction::E).into()]);
-        let mut best_score_at: HashMap<Position, usize> = HashMap::from([(self.start, 0)]);
+        Some(self.find_best_paths()?.cost)
+    }
 
-        while let Some(next) = queue.pop() {
-            let Heading {
-                position,
-                direction,
-                ..
-            } = next;
+    pub fn find_tiles_on_best_paths(&self) -> Option<HashSet<Position>> {
+        let search = self.find_best_paths()?;
 
-            if position == self.end {
-                return Some(next.cost());
-            }
+        Some(
+            search
+                .states_on_optimal_paths()
+                .into_iter()
+                .map(|(position, _)| position)
+                .collect(),
+        )
+    }
+}
 
-            let possible = self.possible_steps_from(&position, &direction);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-            for (new_position, new_direction) in possible {
-                let next_heading = next.new_with(new_position, new_direction);
+    fn best_path(input: &str) -> Option<(usize, usize)> {
+        let map = Map2D::from_string(input).unwrap();
 
-                let score = next_heading.cost();
-                let best_score = best_score_at
-                    .get(&new_position)
-                    .unwrap_or(&usize::max_value());
+        Some((
+            map.find_best_path_cost()?,
+            map.find_tiles_on_best_paths()?.len(),
+        ))
+    }
 
-                if &score > best_score {
-                    break;
-                }
+    #[test]
+    fn follows_straight_corridor() {
+        let input = "#####\n#S.E#\n#####";
+        assert_eq!(best_path(input), Some((2, 3)));
+    }
 
-                best_score_at.insert(position, score);
-                queue.push(next_heading);
-            }
-        }
+    #[test]
+    fn turns_once_towards_end() {
+        let input = "####\n#.E#\n#S.#\n####";
+        assert_eq!(best_path(input), Some((1002, 3)));
+    }
+
+    #[test]
+    fn turns_around_at_start() {
+        let input = "#####\n#E.S#\n#####";
+        assert_eq!(best_path(input), Some((2002, 3)));
+    }
+
+    #[test]
+    fn prefers_fewer_turns_over_fewer_steps() {
+        let input = "#########\n#......E#\n#.#####.#\n#S......#\n#########";
+        assert_eq!(best_path(input), Some((1008, 9)));
+
+        let input = "#######\n#....E#\n#.###.#\n#.#...#\n#.#.#.#\n#S....#\n#######";
+        assert_eq!(best_path(input), Some((1008, 9)));
+    }
 
-        None
+    #[test]
+    fn keeps_equal_cost_branches() {
+        let input = "#######\n#.....#\n#S###E#\n#.....#\n#######";
+        assert_eq!(best_path(input), Some((3006, 12)));
     }
 }
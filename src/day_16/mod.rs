@@ -1,42 +1,63 @@
-mod heading;
 mod map_2d;
 
-use crate::util::read_input;
+use crate::util::Error;
 use map_2d::Map2D;
 
-fn find_best_path_score(input: &str) -> usize {
-    let map = Map2D::from_string(input);
-    let path = map.find_best_path_cost();
+fn find_best_path_score(input: &str) -> Result<Option<usize>, Error> {
+    let map = Map2D::from_string(input)?;
 
-    path.unwrap()
+    Ok(map.find_best_path_cost())
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_16/input.txt");
-
-    find_best_path_score(&input)
+pub fn solve_part_1(input: &str) -> Result<Option<usize>, Error> {
+    find_best_path_score(input)
 }
 
-pub fn solve_part_2() -> usize {
-    let _input = read_input("src/day_16/input.txt");
+fn count_tiles_on_best_paths(input: &str) -> Result<Option<usize>, Error> {
+    let map = Map2D::from_string(input)?;
+
+    Ok(map.find_tiles_on_best_paths().map(|tiles| tiles.len()))
+}
 
-    0
+pub fn solve_part_2(input: &str) -> Result<Option<usize>, Error> {
+    count_tiles_on_best_paths(input)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::find_best_path_score;
+    use super::{count_tiles_on_best_paths, find_best_path_score};
 
     #[test]
     fn finds_best_path_score() {
         let input = "###############\n#.......#....E#\n#.#.###.#.###.#\n#.....#.#...#.#\n#.###.#####.#.#\n#.#.#.......#.#\n#.#.#####.###.#\n#...........#.#\n###.#.#####.#.#\n#...#.....#.#.#\n#.#.#.###.#.#.#\n#.....#...#.#.#\n#.###.#.#.#.#.#\n#S..#.....#...#\n###############";
 
-        let result = find_best_path_score(input);
-        assert_eq!(result, 7036);
+        let result = find_best_path_score(input).unwrap();
+        assert_eq!(result, Some(7036));
 
         let input = "#################\n#...#...#...#..E#\n#.#.#.#.#.#.#.#.#\n#.#.#.#...#...#.#\n#.#.#.#.###.#.#.#\n#...#.#.#.....#.#\n#.#.#.#.#.#####.#\n#.#...#.#.#.....#\n#.#.#####.#.###.#\n#.#.#.......#...#\n#.#.###.#####.###\n#.#.#...#.....#.#\n#.#.#.#####.###.#\n#.#.#.........#.#\n#.#.#.#########.#\n#S#.............#\n#################";
 
-        let result = find_best_path_score(input);
-        assert_eq!(result, 11048);
+        let result = find_best_path_score(input).unwrap();
+        assert_eq!(result, Some(11048));
+    }
+
+    #[test]
+    fn counts_tiles_on_best_paths() {
+        let input = "###############\n#.......#....E#\n#.#.###.#.###.#\n#.....#.#...#.#\n#.###.#####.#.#\n#.#.#.......#.#\n#.#.#####.###.#\n#...........#.#\n###.#.#####.#.#\n#...#.....#.#.#\n#.#.#.###.#.#.#\n#.....#...#.#.#\n#.###.#.#.#.#.#\n#S..#.....#...#\n###############";
+
+        let result = count_tiles_on_best_paths(input).unwrap();
+        assert_eq!(result, Some(45));
+
+        let input = "#################\n#...#...#...#..E#\n#.#.#.#.#.#.#.#.#\n#.#.#.#...#...#.#\n#.#.#.#.###.#.#.#\n#...#.#.#.....#.#\n#.#.#.#.#.#####.#\n#.#...#.#.#.....#\n#.#.#####.#.###.#\n#.#.#.......#...#\n#.#.###.#####.###\n#.#.#...#.....#.#\n#.#.#.#####.###.#\n#.#.#.........#.#\n#.#.#.#########.#\n#S#.............#\n#################";
+
+        let result = count_tiles_on_best_paths(input).unwrap();
+        assert_eq!(result, Some(64));
+    }
+
+    #[test]
+    fn reports_unreachable_end() {
+        let input = "#####\n#S#E#\n#####";
+
+        assert_eq!(find_best_path_score(input).unwrap(), None);
+        assert_eq!(count_tiles_on_best_paths(input).unwrap(), None);
     }
 }
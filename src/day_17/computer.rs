@@ -1,151 +1,353 @@
-#[derive(Debug, Clone)]
-enum OperationType {
-    DivideA,
-    BitwiseXorB,
-    Modulus,
-    Jump,
-    BitwiseXorBC,
-    Write,
-    DivideB,
-    DivideC,
+use super::operation::{disassemble, Operation, OperationType};
+use super::symbolic::{Pass, SingleLoop};
+use super::word::Word;
+use crate::util::{expect_component, parse_number, Error};
+use std::fmt::Display;
+
+const DEFAULT_STEP_LIMIT: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Registers<W = u64> {
+    pub a: W,
+    pub b: W,
+    pub c: W,
 }
 
-#[derive(Debug, Clone)]
-pub struct Operation {
-    operation: OperationType,
-    operand: u64,
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step<W = u64> {
+    pub operation: Operation,
+    pub pointer: usize,
+    pub before: Registers<W>,
+    pub after: Registers<W>,
+    pub output: Option<u64>,
 }
 
-#[derive(Debug)]
-pub struct Computer {
-    register_a: u64,
-    register_b: u64,
-    register_c: u64,
+impl<W: Display> Display for Step<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Registers { a, b, c } = &self.after;
 
-    program: Vec<Operation>,
+        write!(
+            f,
+            "{:>3}: {:<5} A={a} B={b} C={c}",
+            self.pointer, self.operation
+        )?;
+
+        match self.output {
+            Some(output) => write!(f, " -> {output}"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace<W = u64> {
+    pub steps: Vec<Step<W>>,
+    pub fault: Option<MachineError>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MachineError {
+    InvalidOpcode { opcode: u64, pointer: usize },
+    ReservedComboOperand { pointer: usize },
+    StepLimitExceeded { limit: usize },
+}
+
+impl Display for MachineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MachineError::InvalidOpcode { opcode, pointer } => {
+                write!(f, "invalid opcode {opcode} at pointer {pointer}")
+            }
+            MachineError::ReservedComboOperand { pointer } => {
+                write!(f, "reserved combo operand 7 at pointer {pointer}")
+            }
+            MachineError::StepLimitExceeded { limit } => {
+                write!(f, "program did not halt within {limit} steps")
+            }
+        }
+    }
 }
 
-fn extract_register_value(input: &str) -> u64 {
-    input.split(" ").last().unwrap().parse().unwrap()
+impl std::error::Error for MachineError {}
+
+impl From<MachineError> for Error {
+    fn from(error: MachineError) -> Self {
+        Error::Runtime(Box::new(error))
+    }
 }
 
-fn parse_instructions(input: &str) -> Vec<Operation> {
-    let mut result = vec![];
+#[derive(Debug, Clone)]
+pub struct Computer<W = u64> {
+    register_a: W,
+    register_b: W,
+    register_c: W,
+
+    bytes: Vec<u64>,
+    program: Vec<Operation>,
 
-    let mut operation: Option<OperationType> = None;
+    pointer: usize,
+    steps: usize,
+    step_limit: usize,
+}
+
+fn extract_register_value<W: Word>(input: &str) -> Result<W, Error> {
+    let (_, value) = input
+        .rsplit_once(" ")
+        .ok_or(Error::parse(input, "Register <name>: <value>"))?;
+
+    parse_number(value)
+}
 
-    input
-        .split(" ")
-        .last()
-        .unwrap()
+fn parse_program(input: &str) -> Result<Vec<u64>, Error> {
+    let (_, program) = input
+        .rsplit_once(" ")
+        .ok_or(Error::parse(input, "Program: <opcodes>"))?;
+
+    let bytes: Vec<u64> = program
         .split(",")
-        .for_each(|input| {
-            let value = input.parse().unwrap();
-            if operation.is_some() {
-                result.push(Operation {
-                    operation: operation.clone().unwrap(),
-                    operand: value,
-                });
-
-                operation = None;
-                return;
-            }
+        .map(parse_number)
+        .collect::<Result<_, _>>()?;
 
-            operation = Some(match value {
-                0 => OperationType::DivideA,
-                1 => OperationType::BitwiseXorB,
-                2 => OperationType::Modulus,
-                3 => OperationType::Jump,
-                4 => OperationType::BitwiseXorBC,
-                5 => OperationType::Write,
-                6 => OperationType::DivideB,
-                _ => OperationType::DivideC,
-            })
-        });
+    if !bytes.len().is_multiple_of(2) {
+        return Err(Error::parse(program, "an operand after every opcode"));
+    }
+
+    if let Some(byte) = bytes.iter().find(|byte| **byte > 7) {
+        return Err(Error::parse(&byte.to_string(), "a 3-bit number"));
+    }
+
+    Ok(bytes)
+}
 
-    result
+fn parse_instructions(bytes: &[u64]) -> Vec<Operation> {
+    bytes
+        .chunks(2)
+        .filter_map(|pair| Operation::from_bytes(pair[0], pair[1]))
+        .collect()
 }
 
-impl Computer {
-    pub fn from_string(input: &str) -> Self {
+impl<W: Word> Computer<W> {
+    pub fn from_string(input: &str) -> Result<Self, Error> {
         let mut lines = input.split('\n');
-        let a = lines.nth(0).unwrap();
-        let b = lines.nth(0).unwrap();
-        let c = lines.nth(0).unwrap();
-        let instructions = lines.nth(1).unwrap();
-
-        Self {
-            register_a: extract_register_value(a),
-            register_b: extract_register_value(b),
-            register_c: extract_register_value(c),
-            program: parse_instructions(instructions),
+        let mut register = |line: usize, expected: &str| {
+            expect_component(lines.next(), input, expected)
+                .and_then(extract_register_value)
+                .map_err(|error| error.at_line(line))
+        };
+
+        let register_a = register(1, "register A")?;
+        let register_b = register(2, "register B")?;
+        let register_c = register(3, "register C")?;
+
+        let bytes = expect_component(lines.nth(1), input, "a program")
+            .and_then(parse_program)
+            .map_err(|error| error.at_line(5))?;
+
+        Ok(Self {
+            register_a,
+            register_b,
+            register_c,
+            program: parse_instructions(&bytes),
+            bytes,
+            pointer: 0,
+            steps: 0,
+            step_limit: DEFAULT_STEP_LIMIT,
+        })
+    }
+}
+
+impl<W: Word> Computer<W> {
+    pub fn bytes(&self) -> &[u64] {
+        &self.bytes
+    }
+
+    pub fn registers(&self) -> Registers<W> {
+        Registers {
+            a: self.register_a,
+            b: self.register_b,
+            c: self.register_c,
         }
     }
+
+    pub fn set_registers(&mut self, register_a: W, register_b: W, register_c: W) {
+        self.register_a = register_a;
+        self.register_b = register_b;
+        self.register_c = register_c;
+    }
+
+    pub fn set_step_limit(&mut self, step_limit: usize) {
+        self.step_limit = step_limit;
+    }
+
+    pub fn disassemble(&self) -> String {
+        disassemble(&self.program)
+    }
+
+    pub fn symbolic_pass(&self) -> Pass {
+        Pass::from_operations(&self.program)
+    }
+
+    pub fn single_loop(&self) -> Option<SingleLoop> {
+        SingleLoop::from_operations(&self.program)
+    }
 }
 
-fn divide(numerator: u64, combo: u64) -> u64 {
-    let denominator = (2 as u64).pow(combo as u32);
-    (numerator as f64 / denominator as f64).floor() as u64
+fn divide<W: Word>(numerator: W, combo: W) -> W {
+    numerator.shift_right(combo)
 }
 
-fn modulus(value: u64, modulus: u64) -> u64 {
-    value % modulus
+fn modulus<W: Word>(value: W) -> W {
+    value & W::from(7)
 }
 
-fn bitwise_xor(value_a: u64, value_b: u64) -> u64 {
+fn bitwise_xor<W: Word>(value_a: W, value_b: W) -> W {
     value_a ^ value_b
 }
 
-impl Computer {
-    fn combo_operand(&self, operand: &u64) -> Option<u64> {
+impl<W: Word> Computer<W> {
+    fn combo_operand(&self, operand: u64, pointer: usize) -> Result<W, MachineError> {
         match operand {
-            0 | 1 | 2 | 3 => Some(*operand),
-            4 => Some(self.register_a),
-            5 => Some(self.register_b),
-            6 => Some(self.register_c),
-            _ => None,
+            0..=3 => Ok(W::from(operand)),
+            4 => Ok(self.register_a),
+            5 => Ok(self.register_b),
+            6 => Ok(self.register_c),
+            _ => Err(MachineError::ReservedComboOperand { pointer }),
         }
     }
 
-    pub fn output(&mut self) -> String {
-        let mut output: Vec<u64> = vec![];
-        let mut pointer = 0;
-
-        while let Some(computation) = self.program.get(pointer) {
-            let Operation { operation, operand } = computation;
-            let combo = self.combo_operand(operand);
-
-            let Computer {
-                register_a,
-                register_b,
-                register_c,
-                ..
-            } = self;
-
-            pointer += 1;
-
-            match operation {
-                OperationType::DivideA => self.register_a = divide(*register_a, combo.unwrap()),
-                OperationType::BitwiseXorB => self.register_b = bitwise_xor(*register_b, *operand),
-                OperationType::Modulus => self.register_b = modulus(combo.unwrap(), 8),
-                OperationType::Jump => match register_a {
-                    0 => {}
-                    _ => pointer = *operand as usize,
-                },
-                OperationType::BitwiseXorBC => {
-                    self.register_b = bitwise_xor(*register_b, *register_c)
+    pub fn output(&mut self) -> Result<String, MachineError> {
+        let output = self
+            .run()?
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        Ok(output)
+    }
+
+    pub fn step(&mut self) -> Result<Option<Step<W>>, MachineError> {
+        let pointer = self.pointer;
+        let (Some(&opcode), Some(&operand)) =
+            (self.bytes.get(pointer), self.bytes.get(pointer + 1))
+        else {
+            return Ok(None);
+        };
+
+        if self.steps >= self.step_limit {
+            return Err(MachineError::StepLimitExceeded {
+                limit: self.step_limit,
+            });
+        }
+
+        let operation = Operation::from_bytes(opcode, operand)
+            .ok_or(MachineError::InvalidOpcode { opcode, pointer })?;
+        let before = self.registers();
+        let Registers { a, b, c } = before;
+
+        let mut output = None;
+
+        match operation.operation {
+            OperationType::DivideA => {
+                self.register_a = divide(a, self.combo_operand(operand, pointer)?)
+            }
+            OperationType::BitwiseXorB => self.register_b = bitwise_xor(b, W::from(operand)),
+            OperationType::Modulus => {
+                self.register_b = modulus(self.combo_operand(operand, pointer)?)
+            }
+            OperationType::Jump => {}
+            OperationType::BitwiseXorBC => self.register_b = bitwise_xor(b, c),
+            OperationType::Write => {
+                output = Some(modulus(self.combo_operand(operand, pointer)?).low_bits())
+            }
+            OperationType::DivideB => {
+                self.register_b = divide(a, self.combo_operand(operand, pointer)?)
+            }
+            OperationType::DivideC => {
+                self.register_c = divide(a, self.combo_operand(operand, pointer)?)
+            }
+        }
+
+        self.pointer = match operation.operation == OperationType::Jump && a != W::default() {
+            true => operand as usize,
+            false => pointer + 2,
+        };
+        self.steps += 1;
+
+        Ok(Some(Step {
+            operation,
+            pointer,
+            before,
+            after: self.registers(),
+            output,
+        }))
+    }
+
+    pub fn trace(&mut self) -> Trace<W> {
+        let mut steps = vec![];
+
+        loop {
+            match self.step() {
+                Ok(Some(step)) => steps.push(step),
+                Ok(None) => return Trace { steps, fault: None },
+                Err(error) => {
+                    return Trace {
+                        steps,
+                        fault: Some(error),
+                    }
                 }
-                OperationType::Write => output.push(modulus(combo.unwrap(), 8)),
-                OperationType::DivideB => self.register_b = divide(*register_a, combo.unwrap()),
-                OperationType::DivideC => self.register_c = divide(*register_a, combo.unwrap()),
             }
         }
+    }
 
-        output
-            .iter()
-            .map(|value| value.to_string())
-            .collect::<Vec<String>>()
-            .join(",")
+    pub fn run(&mut self) -> Result<Vec<u64>, MachineError> {
+        let mut output: Vec<u64> = vec![];
+
+        while let Some(step) = self.step()? {
+            output.extend(step.output);
+        }
+
+        Ok(output)
+    }
+
+    fn outputs_with_register_a(&self, register_a: W) -> Result<Vec<u64>, MachineError> {
+        let mut computer = self.clone();
+        computer.set_registers(register_a, self.register_b, self.register_c);
+
+        computer.run()
+    }
+
+    fn find_register_a_from(
+        &self,
+        register_a: W,
+        remaining: usize,
+    ) -> Result<Option<W>, MachineError> {
+        if remaining == 0 {
+            return Ok(Some(register_a));
+        }
+
+        let expected = &self.bytes()[remaining - 1..];
+
+        for bits in 0..8 {
+            let candidate = (register_a << 3) | W::from(bits);
+
+            if self.outputs_with_register_a(candidate)? != expected {
+                continue;
+            }
+
+            if let Some(register_a) = self.find_register_a_from(candidate, remaining - 1)? {
+                return Ok(Some(register_a));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn find_self_replicating_register_a(&self) -> Result<Option<W>, MachineError> {
+        match self.single_loop() {
+            Some(single_loop) => Ok(single_loop.invert(&self.bytes)),
+            None => self.find_register_a_from(W::default(), self.bytes.len()),
+        }
     }
 }
 
@@ -157,31 +359,31 @@ mod tests {
     fn determines_result_of_operations() {
         // If register C contains 9, the program 2,6 would set register B to 1.
         let input = "Register A: 0\nRegister B: 0\nRegister C: 9\n\nProgram: 2,6";
-        let mut computer = Computer::from_string(input);
-        computer.output();
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+        computer.output().unwrap();
         assert_eq!(computer.register_b, 1);
 
         // If register A contains 10, the program 5,0,5,1,5,4 would output 0,1,2.
         let input = "Register A: 10\nRegister B: 0\nRegister C: 0\n\nProgram: 5,0,5,1,5,4";
-        let mut computer = Computer::from_string(input);
-        assert_eq!(computer.output(), "0,1,2");
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+        assert_eq!(computer.output().unwrap(), "0,1,2");
 
         // If register A contains 2024, the program 0,1,5,4,3,0 would output 4,2,5,6,7,7,7,7,3,1,0 and leave 0 in register A.
         let input = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0";
-        let mut computer = Computer::from_string(input);
-        assert_eq!(computer.output(), "4,2,5,6,7,7,7,7,3,1,0");
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+        assert_eq!(computer.output().unwrap(), "4,2,5,6,7,7,7,7,3,1,0");
         assert_eq!(computer.register_a, 0);
 
         // If register B contains 29, the program 1,7 would set register B to 26.
         let input = "Register A: 0\nRegister B: 29\nRegister C: 0\n\nProgram: 1,7";
-        let mut computer = Computer::from_string(input);
-        computer.output();
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+        computer.output().unwrap();
         assert_eq!(computer.register_b, 26);
 
         // If register B contains 2024 and register C contains 43690, the program 4,0 would set register B to 44354.
         let input = "Register A: 0\nRegister B: 2024\nRegister C: 43690\n\nProgram: 4,0";
-        let mut computer = Computer::from_string(input);
-        computer.output();
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+        computer.output().unwrap();
         assert_eq!(computer.register_b, 44354);
     }
 
@@ -189,11 +391,205 @@ mod tests {
     fn determines_value_after_running_program() {
         let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0";
 
-        let mut computer = Computer::from_string(input);
+        let mut computer: Computer = Computer::from_string(input).unwrap();
 
         assert_eq!(computer.program.len(), 3);
+        assert_eq!(computer.bytes(), &[0, 1, 5, 4, 3, 0]);
 
-        let result = computer.output();
+        let result = computer.output().unwrap();
         assert_eq!(&result, "4,6,3,5,6,3,5,2,1,0")
     }
+
+    #[test]
+    fn finds_self_replicating_register_a() {
+        let input = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0";
+        let computer: Computer = Computer::from_string(input).unwrap();
+
+        let register_a = computer
+            .find_self_replicating_register_a()
+            .unwrap()
+            .unwrap();
+        assert_eq!(register_a, 117440);
+
+        let mut computer = computer.clone();
+        computer.set_registers(register_a, 0, 0);
+        assert_eq!(computer.output().unwrap(), "0,3,5,4,3,0");
+    }
+
+    #[test]
+    fn agrees_with_symbolic_execution() {
+        let input = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,2,7,5,4,5,1,3,5,5,0,3,3,0";
+        let computer: Computer = Computer::from_string(input).unwrap();
+        let single_loop = computer.single_loop().unwrap();
+        let pass = computer.symbolic_pass();
+
+        for register_a in [1, 7, 2024, 117440, 37221270076916] {
+            let mut computer = computer.clone();
+            computer.set_registers(register_a, 0, 0);
+            let output = computer.run().unwrap();
+
+            let registers = Registers {
+                a: register_a,
+                b: 0,
+                c: 0,
+            };
+            assert_eq!(output[0], pass.outputs[0].evaluate(&registers));
+            assert_eq!(single_loop.outputs_for(register_a)[0], output[0]);
+        }
+    }
+
+    #[test]
+    fn divides_exactly_at_any_width() {
+        let input = "Register A: 9007199254740993\nRegister B: 0\nRegister C: 0\n\nProgram: 0,0";
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+        computer.run().unwrap();
+        assert_eq!(computer.registers().a, 9007199254740993);
+
+        let input =
+            "Register A: 18446744073709551615\nRegister B: 64\nRegister C: 0\n\nProgram: 7,5,6,1";
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+        computer.run().unwrap();
+        assert_eq!(computer.registers().c, 0);
+        assert_eq!(computer.registers().b, 9223372036854775807);
+
+        let input = "Register A: 340282366920938463463374607431768211455\nRegister B: 64\nRegister C: 0\n\nProgram: 7,5,5,6";
+        let mut computer: Computer<u128> = Computer::from_string(input).unwrap();
+        assert_eq!(computer.output().unwrap(), "7");
+        assert_eq!(computer.registers().c, u64::MAX as u128);
+    }
+
+    #[test]
+    fn finds_self_replicating_register_a_with_wide_registers() {
+        let input = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0";
+        let computer: Computer<u128> = Computer::from_string(input).unwrap();
+
+        assert_eq!(
+            computer.find_self_replicating_register_a(),
+            Ok(Some(117440))
+        );
+    }
+
+    #[test]
+    fn traces_each_step() {
+        let input = "Register A: 10\nRegister B: 0\nRegister C: 0\n\nProgram: 5,0,5,1,5,4";
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+
+        let step = computer.step().unwrap().unwrap();
+        let registers = Registers { a: 10, b: 0, c: 0 };
+        assert_eq!(
+            step,
+            Step {
+                operation: Operation::from_bytes(5, 0).unwrap(),
+                pointer: 0,
+                before: registers,
+                after: registers,
+                output: Some(0),
+            }
+        );
+
+        let trace = computer.trace();
+        assert_eq!(trace.fault, None);
+        let pointers: Vec<usize> = trace.steps.iter().map(|step| step.pointer).collect();
+        let outputs: Vec<u64> = trace.steps.iter().filter_map(|step| step.output).collect();
+        assert_eq!(pointers, vec![2, 4]);
+        assert_eq!(outputs, vec![1, 2]);
+        assert_eq!(computer.step(), Ok(None));
+
+        let input = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,3,0";
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+
+        let step = computer.step().unwrap().unwrap();
+        assert_eq!(step.before.a, 2024);
+        assert_eq!(step.after.a, 1012);
+        assert_eq!(step.to_string(), "  0: adv 1 A=1012 B=0 C=0");
+
+        let step = computer.step().unwrap().unwrap();
+        assert_eq!(step.to_string(), "  2: jnz 0 A=1012 B=0 C=0");
+    }
+
+    #[test]
+    fn stops_at_step_limit() {
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,1,3,0";
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+        computer.set_step_limit(10);
+
+        for _ in 0..5 {
+            computer.step().unwrap();
+        }
+        assert_eq!(computer.registers().b, 1);
+
+        let trace = computer.trace();
+        assert_eq!(trace.steps.len(), 5);
+        assert_eq!(trace.steps[4].pointer, 2);
+        assert_eq!(
+            trace.fault,
+            Some(MachineError::StepLimitExceeded { limit: 10 })
+        );
+        assert_eq!(
+            computer.step(),
+            Err(MachineError::StepLimitExceeded { limit: 10 })
+        );
+    }
+
+    #[test]
+    fn reports_reserved_combo_operand() {
+        let input = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7,5,7";
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+
+        assert_eq!(
+            computer.run(),
+            Err(MachineError::ReservedComboOperand { pointer: 2 })
+        );
+
+        let error = Error::from(computer.output().unwrap_err());
+        assert_eq!(error.to_string(), "reserved combo operand 7 at pointer 2");
+    }
+
+    #[test]
+    fn reports_invalid_opcode() {
+        let input = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,3,0";
+        let mut computer: Computer = Computer::from_string(input).unwrap();
+        computer.bytes[2] = 8;
+
+        assert_eq!(
+            computer.run(),
+            Err(MachineError::InvalidOpcode {
+                opcode: 8,
+                pointer: 2
+            })
+        );
+    }
+
+    #[test]
+    fn disassembles_program() {
+        let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0";
+        let computer: Computer = Computer::from_string(input).unwrap();
+
+        assert_eq!(computer.disassemble(), "adv 1\nout A\njnz 0");
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        let input = "Register A: 729\nRegister B: x\nRegister C: 0\n\nProgram: 0,1";
+        let Error::Parse(error) = Computer::<u64>::from_string(input).unwrap_err() else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(error.line, 2);
+        assert_eq!(error.text, "x");
+        assert_eq!(error.expected, "a number");
+
+        let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5";
+        let error = Computer::<u64>::from_string(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 5: expected an operand after every opcode, found '0,1,5'"
+        );
+
+        let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,8,4";
+        let error = Computer::<u64>::from_string(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 5: expected a 3-bit number, found '8'"
+        );
+    }
 }
@@ -1,22 +1,95 @@
 mod computer;
+mod operation;
+mod symbolic;
+mod word;
 
-use crate::util::read_input;
+use crate::util::Error;
 use computer::Computer;
 
-pub fn find_computer_output(input: &str) -> String {
-    let mut computer = Computer::from_string(input);
-    computer.output()
+pub use operation::assemble;
+
+const EXPLAIN_STEP_LIMIT: usize = 10_000;
+
+pub fn find_computer_output(input: &str) -> Result<String, Error> {
+    let mut computer: Computer = Computer::from_string(input)?;
+    Ok(computer.output()?)
+}
+
+pub fn solve_part_1(input: &str) -> Result<String, Error> {
+    find_computer_output(input)
 }
 
-pub fn solve_part_1() -> String {
-    let input = read_input("src/day_17/input.txt");
-    // let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0".to_string();
+pub fn find_self_replicating_register_a(input: &str) -> Result<Option<u64>, Error> {
+    let computer: Computer = Computer::from_string(input)?;
+    Ok(computer.find_self_replicating_register_a()?)
+}
 
-    find_computer_output(&input)
+pub fn solve_part_2(input: &str) -> Result<Option<u64>, Error> {
+    find_self_replicating_register_a(input)
 }
 
-pub fn solve_part_2() -> u128 {
-    let input = read_input("src/day_17/input.txt");
+pub fn explain(input: &str) -> Result<String, Error> {
+    let mut computer: Computer = Computer::from_string(input)?;
+    let program = computer.disassemble();
+    let symbolic = match computer.single_loop() {
+        Some(single_loop) => single_loop.to_string(),
+        None => computer
+            .symbolic_pass()
+            .outputs
+            .iter()
+            .map(|output| format!("out = {output}"))
+            .collect::<Vec<String>>()
+            .join("\n"),
+    };
+    computer.set_step_limit(EXPLAIN_STEP_LIMIT);
+
+    let trace = computer.trace();
+    let mut lines: Vec<String> = trace.steps.iter().map(|step| step.to_string()).collect();
+
+    if let Some(fault) = trace.fault {
+        lines.push(format!("stopped: {fault}"));
+    }
+
+    Ok(format!(
+        "program:\n{program}\nsymbolic:\n{symbolic}\ntrace:\n{}",
+        lines.join("\n")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explains_program_execution() {
+        let input = "Register A: 10\nRegister B: 0\nRegister C: 0\n\nProgram: 5,0,5,1,5,4";
+
+        let result = explain(input).unwrap();
+        assert_eq!(
+            result,
+            "program:\nout 0\nout 1\nout A\nsymbolic:\nout = 0\nout = 1\nout = A & 7\ntrace:\n  0: out 0 A=10 B=0 C=0 -> 0\n  2: out 1 A=10 B=0 C=0 -> 1\n  4: out A A=10 B=0 C=0 -> 2"
+        );
+
+        let input = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0";
+
+        let result = explain(input).unwrap();
+        assert!(result.contains("symbolic:\nout = (A >> 3) & 7\nA = A >> 3 until A = 0\n"));
+    }
+
+    #[test]
+    fn explains_programs_that_do_not_halt() {
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,1,3,0";
 
-    0
+        let result = explain(input).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        let trace = lines.iter().position(|line| *line == "trace:").unwrap();
+        assert_eq!(lines[..3], ["program:", "bxl 1", "jnz 0"]);
+        assert_eq!(lines.len(), trace + EXPLAIN_STEP_LIMIT + 2);
+        assert_eq!(lines[trace + 1], "  0: bxl 1 A=1 B=1 C=0");
+        assert_eq!(lines[trace + 2], "  2: jnz 0 A=1 B=1 C=0");
+        assert_eq!(
+            lines.last(),
+            Some(&"stopped: program did not halt within 10000 steps")
+        );
+    }
 }
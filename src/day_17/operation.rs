@@ -0,0 +1,181 @@
+use crate::util::{parse_lines, parse_number, Error};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperationType {
+    DivideA,
+    BitwiseXorB,
+    Modulus,
+    Jump,
+    BitwiseXorBC,
+    Write,
+    DivideB,
+    DivideC,
+}
+
+const OPERATIONS: [(OperationType, &str); 8] = [
+    (OperationType::DivideA, "adv"),
+    (OperationType::BitwiseXorB, "bxl"),
+    (OperationType::Modulus, "bst"),
+    (OperationType::Jump, "jnz"),
+    (OperationType::BitwiseXorBC, "bxc"),
+    (OperationType::Write, "out"),
+    (OperationType::DivideB, "bdv"),
+    (OperationType::DivideC, "cdv"),
+];
+
+impl OperationType {
+    fn from_opcode(opcode: u64) -> Option<Self> {
+        OPERATIONS
+            .get(opcode as usize)
+            .map(|(operation, _)| operation.clone())
+    }
+
+    fn opcode(&self) -> u64 {
+        OPERATIONS
+            .iter()
+            .position(|(operation, _)| operation == self)
+            .unwrap() as u64
+    }
+
+    fn mnemonic(&self) -> &'static str {
+        OPERATIONS[self.opcode() as usize].1
+    }
+
+    fn takes_combo_operand(&self) -> bool {
+        !matches!(
+            self,
+            OperationType::BitwiseXorB | OperationType::Jump | OperationType::BitwiseXorBC
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    pub operation: OperationType,
+    pub operand: u64,
+}
+
+impl Operation {
+    pub fn from_bytes(opcode: u64, operand: u64) -> Option<Self> {
+        let operation = OperationType::from_opcode(opcode)?;
+
+        Some(Self { operation, operand })
+    }
+
+    pub fn from_string(input: &str) -> Result<Self, Error> {
+        let expected = "<mnemonic> <operand>";
+        let (mnemonic, operand) = input
+            .trim()
+            .split_once(" ")
+            .ok_or(Error::parse(input, expected))?;
+
+        let (operation, _) = OPERATIONS
+            .iter()
+            .find(|(_, name)| name == &mnemonic)
+            .ok_or(Error::parse(
+                mnemonic,
+                "one of adv, bxl, bst, jnz, bxc, out, bdv or cdv",
+            ))?;
+
+        let operand = match (operation.takes_combo_operand(), operand) {
+            (true, "A") => 4,
+            (true, "B") => 5,
+            (true, "C") => 6,
+            _ => parse_number(operand)?,
+        };
+
+        if operand > 7 {
+            return Err(Error::parse(&operand.to_string(), "a 3-bit operand"));
+        }
+
+        Ok(Self {
+            operation: operation.clone(),
+            operand,
+        })
+    }
+
+    pub fn bytes(&self) -> [u64; 2] {
+        [self.operation.opcode(), self.operand]
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operand = match (self.operation.takes_combo_operand(), self.operand) {
+            (true, 4) => "A".to_string(),
+            (true, 5) => "B".to_string(),
+            (true, 6) => "C".to_string(),
+            (_, operand) => operand.to_string(),
+        };
+
+        write!(f, "{} {}", self.operation.mnemonic(), operand)
+    }
+}
+
+pub fn disassemble(operations: &[Operation]) -> String {
+    operations
+        .iter()
+        .map(|operation| operation.to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn assemble(input: &str) -> Result<String, Error> {
+    let operations = parse_lines(input, Operation::from_string)?;
+
+    let bytes: Vec<String> = operations
+        .iter()
+        .flat_map(|operation| operation.bytes())
+        .map(|byte| byte.to_string())
+        .collect();
+
+    Ok(format!("Program: {}", bytes.join(",")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_mnemonics() {
+        assert_eq!(Operation::from_bytes(0, 3).unwrap().to_string(), "adv 3");
+        assert_eq!(Operation::from_bytes(0, 4).unwrap().to_string(), "adv A");
+        assert_eq!(Operation::from_bytes(1, 5).unwrap().to_string(), "bxl 5");
+        assert_eq!(Operation::from_bytes(2, 6).unwrap().to_string(), "bst C");
+        assert_eq!(Operation::from_bytes(3, 0).unwrap().to_string(), "jnz 0");
+        assert_eq!(Operation::from_bytes(5, 5).unwrap().to_string(), "out B");
+    }
+
+    #[test]
+    fn assembles_programs() {
+        let program = "bst A\nbxl 2\ncdv B\nbxc 5\nbxl 3\nout B\nadv 3\njnz 0";
+        let result = assemble(program).unwrap();
+
+        assert_eq!(result, "Program: 2,4,1,2,7,5,4,5,1,3,5,5,0,3,3,0");
+
+        let operations: Vec<Operation> = [2, 4, 1, 2, 7, 5, 4, 5, 1, 3, 5, 5, 0, 3, 3, 0]
+            .chunks(2)
+            .map(|pair| Operation::from_bytes(pair[0], pair[1]).unwrap())
+            .collect();
+        assert_eq!(disassemble(&operations), program);
+    }
+
+    #[test]
+    fn rejects_invalid_assembly() {
+        let error = assemble("adv 3\nmul A").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected one of adv, bxl, bst, jnz, bxc, out, bdv or cdv, found 'mul'"
+        );
+
+        let error = assemble("bxl A").unwrap_err();
+        assert_eq!(error.to_string(), "line 1: expected a number, found 'A'");
+
+        let error = assemble("out 9").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1: expected a 3-bit operand, found '9'"
+        );
+    }
+}
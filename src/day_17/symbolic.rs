@@ -0,0 +1,301 @@
+use super::computer::Registers;
+use super::operation::{Operation, OperationType};
+use super::word::Word;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Constant(u64),
+    Register(char),
+    ShiftRight(Box<Expression>, Box<Expression>),
+    Xor(Box<Expression>, Box<Expression>),
+    And(Box<Expression>, u64),
+}
+
+impl Expression {
+    fn shift_right(value: Expression, amount: Expression) -> Self {
+        match (value, amount) {
+            (value, Expression::Constant(0)) => value,
+            (Expression::Constant(value), Expression::Constant(amount)) => {
+                Expression::Constant(value.shift_right(amount))
+            }
+            (Expression::ShiftRight(value, inner), Expression::Constant(amount)) => match *inner {
+                Expression::Constant(inner) => {
+                    Expression::ShiftRight(value, Box::new(Expression::Constant(inner + amount)))
+                }
+                inner => Expression::ShiftRight(
+                    Box::new(Expression::ShiftRight(value, Box::new(inner))),
+                    Box::new(Expression::Constant(amount)),
+                ),
+            },
+            (value, amount) => Expression::ShiftRight(Box::new(value), Box::new(amount)),
+        }
+    }
+
+    fn xor(left: Expression, right: Expression) -> Self {
+        match (left, right) {
+            (Expression::Constant(left), Expression::Constant(right)) => {
+                Expression::Constant(left ^ right)
+            }
+            (value, Expression::Constant(0)) | (Expression::Constant(0), value) => value,
+            (left, right) => Expression::Xor(Box::new(left), Box::new(right)),
+        }
+    }
+
+    fn and(value: Expression, mask: u64) -> Self {
+        match value {
+            Expression::Constant(value) => Expression::Constant(value & mask),
+            Expression::And(value, inner) => Expression::And(value, inner & mask),
+            value => Expression::And(Box::new(value), mask),
+        }
+    }
+
+    pub fn evaluate<W: Word>(&self, registers: &Registers<W>) -> W {
+        match self {
+            Expression::Constant(value) => W::from(*value),
+            Expression::Register('A') => registers.a,
+            Expression::Register('B') => registers.b,
+            Expression::Register(_) => registers.c,
+            Expression::ShiftRight(value, amount) => value
+                .evaluate(registers)
+                .shift_right(amount.evaluate(registers)),
+            Expression::Xor(left, right) => left.evaluate(registers) ^ right.evaluate(registers),
+            Expression::And(value, mask) => value.evaluate(registers) & W::from(*mask),
+        }
+    }
+
+    pub fn reads(&self, register: char) -> bool {
+        match self {
+            Expression::Constant(_) => false,
+            Expression::Register(name) => *name == register,
+            Expression::ShiftRight(left, right) | Expression::Xor(left, right) => {
+                left.reads(register) || right.reads(register)
+            }
+            Expression::And(value, _) => value.reads(register),
+        }
+    }
+
+    fn operand(&self) -> String {
+        match self {
+            Expression::Constant(_) | Expression::Register(_) => self.to_string(),
+            _ => format!("({self})"),
+        }
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expression::Constant(value) => write!(f, "{value}"),
+            Expression::Register(name) => write!(f, "{name}"),
+            Expression::ShiftRight(value, amount) => {
+                write!(f, "{} >> {}", value.operand(), amount.operand())
+            }
+            Expression::Xor(left, right) => write!(f, "{} ^ {}", left.operand(), right.operand()),
+            Expression::And(value, mask) => write!(f, "{} & {mask}", value.operand()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pass {
+    pub a: Expression,
+    pub b: Expression,
+    pub c: Expression,
+    pub outputs: Vec<Expression>,
+}
+
+impl Pass {
+    fn combo_operand(&self, operand: u64) -> Expression {
+        match operand {
+            4 => self.a.clone(),
+            5 => self.b.clone(),
+            6 => self.c.clone(),
+            _ => Expression::Constant(operand),
+        }
+    }
+
+    pub fn from_operations(operations: &[Operation]) -> Self {
+        let mut pass = Pass {
+            a: Expression::Register('A'),
+            b: Expression::Register('B'),
+            c: Expression::Register('C'),
+            outputs: vec![],
+        };
+
+        for Operation { operation, operand } in operations {
+            let combo = pass.combo_operand(*operand);
+
+            match operation {
+                OperationType::DivideA => pass.a = Expression::shift_right(pass.a.clone(), combo),
+                OperationType::BitwiseXorB => {
+                    pass.b = Expression::xor(pass.b.clone(), Expression::Constant(*operand))
+                }
+                OperationType::Modulus => pass.b = Expression::and(combo, 7),
+                OperationType::Jump => {}
+                OperationType::BitwiseXorBC => {
+                    pass.b = Expression::xor(pass.b.clone(), pass.c.clone())
+                }
+                OperationType::Write => pass.outputs.push(Expression::and(combo, 7)),
+                OperationType::DivideB => pass.b = Expression::shift_right(pass.a.clone(), combo),
+                OperationType::DivideC => pass.c = Expression::shift_right(pass.a.clone(), combo),
+            }
+        }
+
+        pass
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SingleLoop {
+    pub shift: u64,
+    pub outputs: Vec<Expression>,
+}
+
+impl SingleLoop {
+    pub fn from_operations(operations: &[Operation]) -> Option<Self> {
+        let (last, body) = operations.split_last()?;
+
+        if last.operation != OperationType::Jump || last.operand != 0 {
+            return None;
+        }
+
+        let control: Vec<&Operation> = body
+            .iter()
+            .filter(|operation| {
+                matches!(
+                    operation.operation,
+                    OperationType::DivideA | OperationType::Jump
+                )
+            })
+            .collect();
+
+        let shift = match control.as_slice() {
+            [Operation {
+                operation: OperationType::DivideA,
+                operand: shift @ 1..=3,
+            }] => *shift,
+            _ => return None,
+        };
+
+        let pass = Pass::from_operations(body);
+        let register_a =
+            Expression::shift_right(Expression::Register('A'), Expression::Constant(shift));
+
+        if pass.a != register_a || pass.outputs.is_empty() {
+            return None;
+        }
+
+        match pass
+            .outputs
+            .iter()
+            .any(|output| output.reads('B') || output.reads('C'))
+        {
+            true => None,
+            false => Some(Self {
+                shift,
+                outputs: pass.outputs,
+            }),
+        }
+    }
+
+    pub fn outputs_for<W: Word>(&self, register_a: W) -> Vec<u64> {
+        let registers = Registers {
+            a: register_a,
+            b: W::default(),
+            c: W::default(),
+        };
+
+        self.outputs
+            .iter()
+            .map(|output| output.evaluate(&registers).low_bits())
+            .collect()
+    }
+
+    fn invert_from<W: Word>(&self, register_a: W, expected: &[u64]) -> Option<W> {
+        if expected.is_empty() {
+            return Some(register_a);
+        }
+
+        let (remaining, group) = expected.split_at(expected.len() - self.outputs.len());
+
+        (0..1 << self.shift).find_map(|bits| {
+            let candidate = (register_a << self.shift as u32) | W::from(bits);
+
+            match candidate != W::default() && self.outputs_for(candidate) == group {
+                true => self.invert_from(candidate, remaining),
+                false => None,
+            }
+        })
+    }
+
+    pub fn invert<W: Word>(&self, expected: &[u64]) -> Option<W> {
+        match expected.len().is_multiple_of(self.outputs.len()) {
+            true => self.invert_from(W::default(), expected),
+            false => None,
+        }
+    }
+}
+
+impl Display for SingleLoop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for output in &self.outputs {
+            writeln!(f, "out = {output}")?;
+        }
+
+        write!(f, "A = A >> {} until A = 0", self.shift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operations(bytes: &[u64]) -> Vec<Operation> {
+        bytes
+            .chunks(2)
+            .map(|pair| Operation::from_bytes(pair[0], pair[1]).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn describes_outputs_of_a_pass() {
+        let pass = Pass::from_operations(&operations(&[0, 3, 5, 4, 3, 0]));
+        assert_eq!(pass.outputs[0].to_string(), "(A >> 3) & 7");
+
+        let pass = Pass::from_operations(&operations(&[2, 4, 1, 5, 5, 5, 0, 3, 3, 0]));
+        assert_eq!(pass.outputs[0].to_string(), "((A & 7) ^ 5) & 7");
+        assert_eq!(pass.a.to_string(), "A >> 3");
+
+        let pass = Pass::from_operations(&operations(&[0, 1, 0, 2, 5, 6]));
+        assert_eq!(pass.a.to_string(), "A >> 3");
+        assert_eq!(pass.outputs[0].to_string(), "C & 7");
+    }
+
+    #[test]
+    fn detects_single_loops() {
+        let program = [2, 4, 1, 2, 7, 5, 4, 5, 1, 3, 5, 5, 0, 3, 3, 0];
+        let single_loop = SingleLoop::from_operations(&operations(&program)).unwrap();
+
+        assert_eq!(single_loop.shift, 3);
+        assert_eq!(
+            single_loop.to_string(),
+            "out = ((((A & 7) ^ 2) ^ (A >> ((A & 7) ^ 2))) ^ 3) & 7\nA = A >> 3 until A = 0"
+        );
+
+        assert!(SingleLoop::from_operations(&operations(&[5, 0, 5, 1, 5, 4])).is_none());
+        assert!(SingleLoop::from_operations(&operations(&[5, 5, 0, 3, 3, 0])).is_none());
+        assert!(SingleLoop::from_operations(&operations(&[0, 4, 5, 4, 3, 0])).is_none());
+        assert!(SingleLoop::from_operations(&operations(&[0, 3, 5, 4, 3, 2])).is_none());
+    }
+
+    #[test]
+    fn inverts_single_loops() {
+        let program = [0, 3, 5, 4, 3, 0];
+        let single_loop = SingleLoop::from_operations(&operations(&program)).unwrap();
+
+        assert_eq!(single_loop.invert(&program), Some(117440_u64));
+        assert_eq!(single_loop.invert(&program), Some(117440_u128));
+        assert_eq!(single_loop.invert::<u64>(&[5]), None);
+    }
+}
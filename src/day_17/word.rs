@@ -0,0 +1,68 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{BitAnd, BitOr, BitXor, Shl},
+    str::FromStr,
+};
+
+pub trait Word:
+    Copy
+    + Debug
+    + Display
+    + Default
+    + PartialEq
+    + FromStr
+    + From<u64>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Shl<u32, Output = Self>
+{
+    fn shift_right(self, amount: Self) -> Self;
+
+    fn low_bits(self) -> u64;
+}
+
+impl Word for u64 {
+    fn shift_right(self, amount: Self) -> Self {
+        u32::try_from(amount)
+            .ok()
+            .and_then(|amount| self.checked_shr(amount))
+            .unwrap_or(0)
+    }
+
+    fn low_bits(self) -> u64 {
+        self
+    }
+}
+
+impl Word for u128 {
+    fn shift_right(self, amount: Self) -> Self {
+        u32::try_from(amount)
+            .ok()
+            .and_then(|amount| self.checked_shr(amount))
+            .unwrap_or(0)
+    }
+
+    fn low_bits(self) -> u64 {
+        self as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shifts_exactly_and_saturates() {
+        let large: u64 = (1 << 60) + 1;
+        assert_eq!(large.shift_right(1), 1 << 59);
+        assert_eq!(large.shift_right(63), 0);
+        assert_eq!(large.shift_right(64), 0);
+        assert_eq!(large.shift_right(u64::MAX), 0);
+
+        let wide: u128 = (1 << 100) + 7;
+        assert_eq!(wide.shift_right(3), 1 << 97);
+        assert_eq!(wide.shift_right(128), 0);
+        assert_eq!(wide.low_bits(), 7);
+    }
+}
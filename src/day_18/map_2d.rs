@@ -1,123 +1,297 @@
-use crate::util::{move_steps_in_direction, Direction, Position};
-use std::{
-    collections::{BinaryHeap, HashMap},
-    isize,
+use crate::util::{
+    a_star, manhattan_distance, parse_lines, parse_number, Direction, Error, Grid, Position, Route,
 };
 
-use super::path::Path;
-
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 enum MapFeature {
     Empty,
     Obstructed,
+    FallsAt(usize),
+}
+
+impl MapFeature {
+    fn is_open_at(&self, time: usize) -> bool {
+        match self {
+            MapFeature::Empty => true,
+            MapFeature::Obstructed => false,
+            MapFeature::FallsAt(fall) => time < *fall,
+        }
+    }
 }
 
 pub struct Map2D {
-    tiles: HashMap<Position, MapFeature>,
-    size: (isize, isize),
+    tiles: Grid<MapFeature>,
+    start: Position,
+    goals: Vec<Position>,
+    settles_at: usize,
 }
 
-pub fn coordinate_from_string(input: &str) -> Position {
-    let mut components = input.split(',');
-    let x: isize = components.nth(0).unwrap().parse().unwrap();
-    let y: isize = components.nth(0).unwrap().parse().unwrap();
+pub fn coordinate_from_string(input: &str) -> Result<Position, Error> {
+    let (x, y) = input
+        .split_once(',')
+        .ok_or(Error::parse(input, "<x>,<y>"))?;
+
+    Ok((parse_number(x)?, parse_number(y)?))
+}
 
-    (x, y)
+pub fn grid_size(walls: &[Position]) -> (isize, isize) {
+    walls.iter().fold((0, 0), |(width, height), (x, y)| {
+        (width.max(*x), height.max(*y))
+    })
 }
 
 impl Map2D {
-    pub fn from_string(input: &str, size: (isize, isize), add_walls: usize) -> Self {
-        let mut tiles = HashMap::new();
+    pub fn from_walls(
+        size: (isize, isize),
+        walls: &[Position],
+        start: Position,
+        goals: Vec<Position>,
+    ) -> Self {
+        let mut tiles = Grid::new(size.0 as usize + 1, size.1 as usize + 1, MapFeature::Empty);
 
-        for x in 0..=size.0 {
-            for y in 0..=size.1 {
-                tiles.insert((x, y), MapFeature::Empty);
-            }
+        for position in walls {
+            tiles.set(position, MapFeature::Obstructed);
         }
 
-        input.lines().take(add_walls).for_each(|line| {
-            let position = coordinate_from_string(line);
+        Self {
+            tiles,
+            start,
+            goals,
+            settles_at: 0,
+        }
+    }
 
-            tiles.insert(position, MapFeature::Obstructed);
-        });
+    pub fn from_falling_walls(
+        size: (isize, isize),
+        walls: &[Position],
+        start: Position,
+        goals: Vec<Position>,
+    ) -> Self {
+        let mut tiles = Grid::new(size.0 as usize + 1, size.1 as usize + 1, MapFeature::Empty);
+
+        for (time, position) in walls.iter().enumerate().rev() {
+            tiles.set(position, MapFeature::FallsAt(time));
+        }
 
-        Self { tiles, size }
+        Self {
+            tiles,
+            start,
+            goals,
+            settles_at: walls.len(),
+        }
+    }
+
+    pub fn from_string(input: &str, add_walls: usize) -> Result<Self, Error> {
+        let walls = parse_lines(input, coordinate_from_string)?;
+
+        let size = grid_size(&walls);
+
+        Ok(Self::from_walls(
+            size,
+            &walls[..add_walls.min(walls.len())],
+            (0, 0),
+            vec![size],
+        ))
     }
 }
 
-fn construct_path(
-    path_map: &HashMap<Position, Position>,
-    start: (isize, isize),
-    end: (isize, isize),
-) -> Vec<Position> {
-    let mut result = vec![];
+pub fn find_blocking_byte(walls: &[Position]) -> Option<(usize, Position)> {
+    let size = grid_size(walls);
+    let is_blocked = |count: usize| {
+        Map2D::from_walls(size, &walls[..count], (0, 0), vec![size])
+            .best_path()
+            .is_none()
+    };
+
+    if !is_blocked(walls.len()) {
+        return None;
+    }
 
-    let mut next = &end;
+    let (mut low, mut high) = (0, walls.len() - 1);
 
-    while next != &start {
-        result.push(next.clone());
-        next = path_map.get(next).unwrap();
+    while low < high {
+        let middle = (low + high) / 2;
+
+        match is_blocked(middle + 1) {
+            true => high = middle,
+            false => low = middle + 1,
+        }
     }
 
-    result
+    Some((low, walls[low]))
 }
 
 impl Map2D {
-    pub fn add_wall_from_string(&mut self, input: &str) {
-        let position = coordinate_from_string(input);
-        self.tiles.insert(position, MapFeature::Obstructed);
+    fn possible_steps_from(&self, position: &Position) -> Vec<Position> {
+        self.tiles
+            .neighbours(
+                position,
+                [Direction::E, Direction::S, Direction::W, Direction::N],
+            )
+            .filter_map(|(next, feature)| match feature {
+                MapFeature::Empty => Some(next),
+                _ => None,
+            })
+            .collect()
     }
 
-    fn possible_steps_from(&self, position: &Position) -> Vec<Position> {
-        [Direction::E, Direction::S, Direction::W, Direction::N]
+    fn distance_to_nearest_goal(&self, position: &Position) -> usize {
+        self.goals
             .iter()
-            .filter_map(|direction| {
-                let next = move_steps_in_direction(position, 1, direction);
-                match self.tiles.get(&next) {
-                    None => None,
-                    Some(feature) => match feature {
-                        MapFeature::Empty => Some(next),
-                        _ => None,
-                    },
-                }
+            .map(|goal| manhattan_distance(position, goal))
+            .min()
+            .unwrap_or_default()
+    }
+
+    fn possible_steps_at(&self, (position, time): &(Position, usize)) -> Vec<(Position, usize)> {
+        let time = (time + 1).min(self.settles_at);
+
+        self.tiles
+            .neighbours(
+                position,
+                [Direction::E, Direction::S, Direction::W, Direction::N],
+            )
+            .filter_map(|(next, feature)| match feature.is_open_at(time) {
+                true => Some((next, time)),
+                false => None,
             })
             .collect()
     }
 
-    pub fn best_path(&self) -> Option<Vec<Position>> {
-        let start = (0, 0);
-        let end = self.size;
+    pub fn best_path(&self) -> Option<Route> {
+        if !self.tiles.get(&self.start)?.is_open_at(0) {
+            return None;
+        }
 
-        let mut distances: HashMap<Position, isize> = HashMap::from([(start, 0)]);
-        let mut came_from: HashMap<Position, Position> = HashMap::new();
-        let mut queue: BinaryHeap<Path> = BinaryHeap::from([Path::new(start, 0)]);
+        a_star(
+            self.start,
+            |position| self.possible_steps_from(position),
+            |_, _| 1,
+            |position| self.distance_to_nearest_goal(position),
+            |position| self.goals.contains(position),
+        )
+        .map(Route::from)
+    }
 
-        distances.insert(start, 0);
+    pub fn best_path_while_falling(&self) -> Option<Route> {
+        if !self.tiles.get(&self.start)?.is_open_at(0) {
+            return None;
+        }
 
-        while let Some(path) = queue.pop() {
-            let Path { position, .. } = path;
+        let search = a_star(
+            (self.start, 0),
+            |state| self.possible_steps_at(state),
+            |_, _| 1,
+            |(position, _)| self.distance_to_nearest_goal(position),
+            |(position, _)| self.goals.contains(position),
+        )?;
 
-            for neighbor in self.possible_steps_from(&position) {
-                if neighbor == end {
-                    came_from.insert(end, position);
+        Route::from_tiles(
+            search
+                .path
+                .into_iter()
+                .map(|(position, _)| position)
+                .collect(),
+        )
+    }
+}
 
-                    let path = construct_path(&came_from, start, end);
-                    return Some(path);
-                }
+impl Map2D {
+    pub fn draw_route(&self, route: &Route) -> String {
+        let mut picture = self.tiles.map(|feature| match feature {
+            MapFeature::Empty => '.',
+            _ => '#',
+        });
 
-                let new_distance = distances.get(&position).unwrap() + 1;
-                let distance = distances.get(&neighbor).unwrap_or(&isize::MAX);
+        for (from, to) in route.steps() {
+            let arrow = match (to.0 - from.0, to.1 - from.1) {
+                (1, 0) => '>',
+                (-1, 0) => '<',
+                (0, 1) => 'v',
+                _ => '^',
+            };
 
-                if &new_distance >= distance {
-                    continue;
-                }
+            picture.set(&from, arrow);
+        }
 
-                distances.insert(neighbor, new_distance);
-                came_from.insert(neighbor, position);
-                queue.push(Path::new(neighbor, new_distance));
-            }
+        if let Some(end) = route.tiles().last() {
+            picture.set(end, 'E');
         }
 
-        None
+        picture.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infers_grid_size_from_walls() {
+        assert_eq!(grid_size(&[(5, 4), (4, 2), (0, 6)]), (5, 6));
+        assert_eq!(grid_size(&[]), (0, 0));
+    }
+
+    #[test]
+    fn finds_nearest_reachable_goal() {
+        let walls = [(1, 0), (1, 1), (1, 2)];
+
+        let map = Map2D::from_walls((4, 4), &walls, (0, 0), vec![(4, 4), (2, 0), (9, 9)]);
+        let route = map.best_path().unwrap();
+        assert_eq!(route.cost(), 8);
+        assert_eq!(route.tiles().last(), Some(&(4, 4)));
+
+        let map = Map2D::from_walls((4, 4), &walls, (0, 0), vec![(9, 9)]);
+        assert!(map.best_path().is_none());
+
+        let map = Map2D::from_walls((4, 4), &walls, (4, 0), vec![(0, 0), (2, 0)]);
+        let route = map.best_path().unwrap();
+        assert_eq!(route.cost(), 2);
+        assert_eq!(route.tiles(), &[(4, 0), (3, 0), (2, 0)]);
+
+        let map = Map2D::from_walls((4, 4), &walls, (4, 0), vec![(4, 0)]);
+        let route = map.best_path().unwrap();
+        assert_eq!(route.cost(), 0);
+        assert_eq!(route.tiles(), &[(4, 0)]);
+
+        let map = Map2D::from_walls((4, 4), &walls, (1, 0), vec![(4, 0)]);
+        assert!(map.best_path().is_none());
+    }
+
+    #[test]
+    fn draws_route_on_map() {
+        let map = Map2D::from_walls((2, 2), &[(1, 0), (1, 1)], (0, 0), vec![(2, 0)]);
+        let route = map.best_path().unwrap();
+
+        assert_eq!(map.draw_route(&route), "v#E\nv#^\n>>^");
+    }
+
+    #[test]
+    fn avoids_bytes_as_they_fall() {
+        let walls = [(5, 5), (5, 5), (2, 0), (1, 0)];
+        let map = Map2D::from_falling_walls((2, 0), &walls, (0, 0), vec![(2, 0)]);
+        assert!(map.best_path_while_falling().is_none());
+
+        let walls = [(5, 5), (5, 5), (5, 5), (2, 0), (1, 0)];
+        let map = Map2D::from_falling_walls((2, 0), &walls, (0, 0), vec![(2, 0)]);
+        let route = map.best_path_while_falling().unwrap();
+        assert_eq!(route.cost(), 2);
+        assert_eq!(route.tiles(), &[(0, 0), (1, 0), (2, 0)]);
+
+        let walls = [(1, 0), (5, 5), (5, 5), (5, 5)];
+        let map = Map2D::from_falling_walls((2, 1), &walls, (0, 0), vec![(2, 1)]);
+        let route = map.best_path_while_falling().unwrap();
+        assert_eq!(route.cost(), 3);
+        assert_eq!(route.tiles()[1], (0, 1));
+
+        let map = Map2D::from_falling_walls((2, 1), &[(0, 0)], (0, 0), vec![(2, 1)]);
+        assert!(map.best_path_while_falling().is_none());
+    }
+
+    #[test]
+    fn stops_searching_once_every_byte_has_fallen() {
+        let walls = [(5, 5), (5, 5), (2, 1), (1, 2)];
+        let map = Map2D::from_falling_walls((2, 2), &walls, (0, 0), vec![(2, 2)]);
+        assert!(map.best_path_while_falling().is_none());
     }
 }
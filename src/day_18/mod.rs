@@ -1,50 +1,58 @@
 mod map_2d;
-mod path;
 
-use map_2d::{coordinate_from_string, Map2D};
+use map_2d::{coordinate_from_string, find_blocking_byte, grid_size, Map2D};
 
-use crate::util::{read_input, Position};
+use crate::util::{parse_lines, Error, Position};
 
-pub fn find_shortest_path_in_map(
-    input: &str,
-    size: (isize, isize),
-    add_walls: usize,
-) -> Option<usize> {
-    let map = Map2D::from_string(input, size, add_walls);
+pub fn find_shortest_path_in_map(input: &str, add_walls: usize) -> Result<Option<usize>, Error> {
+    let map = Map2D::from_string(input, add_walls)?;
 
-    map.best_path().map(|entry| entry.len())
+    Ok(map.best_path().map(|route| route.cost()))
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_18/input.txt");
-
-    find_shortest_path_in_map(&input, (70, 70), 1024).unwrap()
+pub fn solve_part_1(input: &str) -> Result<Option<usize>, Error> {
+    find_shortest_path_in_map(input, 1024)
 }
 
-pub fn solve_part_2() -> Option<String> {
-    let input = read_input("src/day_18/input.txt");
-    let base_walls = 1024;
+pub fn find_shortest_path_while_falling(input: &str) -> Result<Option<usize>, Error> {
+    let walls = parse_lines(input, coordinate_from_string)?;
+    let size = grid_size(&walls);
+    let map = Map2D::from_falling_walls(size, &walls, (0, 0), vec![size]);
+
+    Ok(map.best_path_while_falling().map(|route| route.cost()))
+}
 
-    let mut map = Map2D::from_string(&input, (70, 70), base_walls);
-    let mut path: Vec<Position> = map.best_path().unwrap();
+pub fn find_first_blocking_byte(input: &str) -> Result<Option<(usize, Position)>, Error> {
+    let walls = parse_lines(input, coordinate_from_string)?;
 
-    for line in input.lines().skip(base_walls) {
-        let position = coordinate_from_string(line);
-        map.add_wall_from_string(line);
+    Ok(find_blocking_byte(&walls))
+}
 
-        if !path.contains(&position) {
-            continue;
-        }
+pub fn solve_part_2(input: &str) -> Result<Option<String>, Error> {
+    let blocking = find_first_blocking_byte(input)?;
 
-        let new_path = map.best_path();
+    Ok(blocking.map(|(_, (x, y))| format!("{x},{y}")))
+}
 
-        match new_path {
-            None => return Some(line.to_string()),
-            Some(new_path) => path = new_path,
-        }
-    }
+fn explain_after(input: &str, add_walls: usize) -> Result<String, Error> {
+    let map = Map2D::from_string(input, add_walls)?;
+    let route = match map.best_path() {
+        Some(route) => format!("{} steps\n{}", route.cost(), map.draw_route(&route)),
+        None => "none".to_string(),
+    };
+
+    let escape = match find_shortest_path_while_falling(input)? {
+        Some(steps) => format!("{steps} steps"),
+        None => "none".to_string(),
+    };
+
+    Ok(format!(
+        "route after {add_walls} bytes: {route}\nescape while bytes fall: {escape}"
+    ))
+}
 
-    None
+pub fn explain(input: &str) -> Result<String, Error> {
+    explain_after(input, 1024)
 }
 
 #[cfg(test)]
@@ -55,7 +63,43 @@ mod tests {
     fn determines_best_path_length() {
         let input = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0";
 
-        let result = find_shortest_path_in_map(input, (6, 6), 12);
+        let result = find_shortest_path_in_map(input, 12).unwrap();
         assert_eq!(result, Some(22));
     }
+
+    #[test]
+    fn determines_best_path_length_while_falling() {
+        let input = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0";
+
+        let result = find_shortest_path_while_falling(input).unwrap();
+        assert_eq!(result, Some(12));
+    }
+
+    #[test]
+    fn explains_route_and_escape() {
+        let input = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0";
+
+        let result = explain_after(input, 12).unwrap();
+        assert_eq!(
+            result,
+            "route after 12 bytes: 22 steps\n>v.#>>v\n.v#>^#v\n.>>^#v<\n...#v<#\n..#v<#.\n.#.v#..\n#.#>>>E\nescape while bytes fall: 12 steps"
+        );
+
+        let result = explain_after(input, 25).unwrap();
+        assert_eq!(
+            result,
+            "route after 25 bytes: none\nescape while bytes fall: 12 steps"
+        );
+    }
+
+    #[test]
+    fn finds_first_blocking_byte() {
+        let input = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0";
+
+        let result = find_first_blocking_byte(input).unwrap();
+        assert_eq!(result, Some((20, (6, 1))));
+
+        let result = find_first_blocking_byte("5,4\n4,2\n6,0\n0,6").unwrap();
+        assert_eq!(result, None);
+    }
 }
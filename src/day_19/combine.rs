@@ -1,118 +1,140 @@
-use std::{
-    cmp::Ordering,
-    collections::{BinaryHeap, HashSet},
-};
+use super::{towel::Towel, trie::Trie};
+use crate::util::{expect_component, line_after, parse_lines, Error};
 
-use super::towel::Towel;
-
-pub fn towel_combinations_from_string(input: &str) -> (Vec<Towel>, Vec<Towel>) {
+pub fn towels_from_string(input: &str) -> Result<(Vec<Towel>, Vec<Towel>), Error> {
     let mut components = input.split("\n\n");
 
-    let available = Towel::many_from_string(components.nth(0).unwrap(), ", ");
-    let combinations = Towel::many_from_string(components.nth(0).unwrap(), "\n");
+    let available = expect_component(components.next(), input, "available towel patterns")?;
+    let combinations =
+        expect_component(components.next(), input, "a blank line before the designs")?;
 
-    let mut unused = vec![];
+    let combinations = parse_lines(combinations, Towel::from_string)
+        .map_err(|error| error.at_line(line_after(available)))?;
+    let available = Towel::many_from_string(available, ", ")?;
 
-    for towel in available.iter() {
-        let available = available
-            .clone()
-            .into_iter()
-            .filter(|inner| inner != towel)
+    Ok((available, combinations))
+}
+
+impl Towel {
+    fn arrangements<T: Copy>(
+        &self,
+        patterns: &Trie,
+        none: T,
+        one: T,
+        add: impl Fn(T, T) -> T,
+    ) -> T {
+        let desired = &self.stripes;
+        let mut ways = vec![none; desired.len() + 1];
+        ways[0] = one;
+
+        for offset in 0..desired.len() {
+            let current = ways[offset];
+
+            for length in patterns.prefix_lengths(&desired[offset..]) {
+                ways[offset + length] = add(ways[offset + length], current);
+            }
+        }
+
+        ways[desired.len()]
+    }
+
+    pub fn is_combination_possible(&self, patterns: &Trie) -> bool {
+        self.arrangements(patterns, false, true, |a, b| a || b)
+    }
+
+    pub fn count_combinations(&self, patterns: &Trie) -> u64 {
+        self.arrangements(patterns, 0, 1, |a, b| a + b)
+    }
+
+    fn fewest_patterns_from(&self, patterns: &Trie) -> Vec<Option<usize>> {
+        let desired = &self.stripes;
+        let mut fewest = vec![None; desired.len() + 1];
+        fewest[desired.len()] = Some(0);
+
+        for offset in (0..desired.len()).rev() {
+            fewest[offset] = patterns
+                .prefix_lengths(&desired[offset..])
+                .filter_map(|length| fewest[offset + length])
+                .min()
+                .map(|count| count + 1);
+        }
+
+        fewest
+    }
+
+    fn with_pieces(&self, pieces: &[usize]) -> Towel {
+        let mut offset = 0;
+        let id = pieces
+            .iter()
+            .map(|length| {
+                offset += length;
+                self.stripes[offset - length..offset].iter().collect()
+            })
             .collect();
 
-        if towel.is_combination_possible(&available) {
-            unused.push(towel);
+        Towel {
+            id,
+            stripes: self.stripes.clone(),
         }
     }
 
-    let available = available
-        .iter()
-        .filter_map(|entry| match unused.contains(&entry) {
-            true => None,
-            false => Some(entry.clone()),
-        })
-        .collect();
+    pub fn find_combination(&self, patterns: &Trie) -> Option<Towel> {
+        let fewest = self.fewest_patterns_from(patterns);
+        let mut remaining = fewest[0]?;
+        let mut pieces = vec![];
+        let mut offset = 0;
 
-    (available, combinations)
-}
+        while remaining > 0 {
+            let length = patterns
+                .prefix_lengths(&self.stripes[offset..])
+                .find(|length| fewest[offset + length] == Some(remaining - 1))?;
+
+            pieces.push(length);
+            offset += length;
+            remaining -= 1;
+        }
 
-fn stripes_match(a: &[char], b: &[char]) -> bool {
-    if a.len() != b.len() {
-        return false;
+        Some(self.with_pieces(&pieces))
     }
 
-    a.iter().enumerate().all(|(i, stripe)| {
-        let target = b.get(i);
-        match target {
-            Some(target) => target == stripe,
-            _ => false,
+    fn collect_combinations(
+        &self,
+        patterns: &Trie,
+        fewest: &[Option<usize>],
+        pieces: &mut Vec<usize>,
+        offset: usize,
+        limit: usize,
+        found: &mut Vec<Towel>,
+    ) {
+        if offset == self.stripes.len() {
+            found.push(self.with_pieces(pieces));
+            return;
         }
-    })
-}
 
-fn find_relevant<'a>(available: &[Towel], target: &[char], skip: usize) -> Vec<Towel> {
-    available
-        .iter()
-        .filter_map(|towel| {
-            let target: Vec<char> = target
-                .iter()
-                .skip(skip)
-                .take(towel.stripes.len())
-                .map(|c| *c)
-                .collect();
-
-            match stripes_match(&towel.stripes, &target) {
-                true => Some(towel.clone()),
-                _ => None,
+        for length in patterns.prefix_lengths(&self.stripes[offset..]) {
+            if found.len() == limit {
+                return;
             }
-        })
-        .collect()
-}
 
-impl Towel {
-    pub fn is_combination_possible(&self, available: &Vec<Towel>) -> bool {
-        let Towel {
-            stripes: desired, ..
-        } = self;
-
-        let mut checked: HashSet<String> = HashSet::new();
-
-        let start_nodes = find_relevant(available, &desired, 0);
-        let mut queue: BinaryHeap<Towel> = BinaryHeap::from(start_nodes);
-
-        while let Some(next) = queue.pop() {
-            let id = next.format_id();
-            if checked.iter().any(|pattern| {
-                stripes_match(
-                    &pattern.chars().collect::<Vec<char>>(),
-                    &id.chars().collect::<Vec<char>>(),
-                )
-            }) {
+            if fewest[offset + length].is_none() {
                 continue;
             }
 
-            let Towel { stripes, .. } = &next;
-            let relevant = find_relevant(available, &desired, stripes.len());
-
-            for towel in relevant {
-                let new_towel = next.copy(towel);
+            pieces.push(length);
+            self.collect_combinations(patterns, fewest, pieces, offset + length, limit, found);
+            pieces.pop();
+        }
+    }
 
-                let Towel { stripes, .. } = &new_towel;
-                let id = new_towel.format_id();
+    pub fn first_combinations(&self, patterns: &Trie, limit: usize) -> Vec<Towel> {
+        let fewest = self.fewest_patterns_from(patterns);
+        let mut found = vec![];
 
-                match stripes.len().cmp(&desired.len()) {
-                    Ordering::Equal => return true,
-                    Ordering::Greater => {
-                        checked.insert(id);
-                    }
-                    Ordering::Less => {
-                        queue.push(new_towel);
-                    }
-                }
-            }
+        if limit > 0 {
+            self.collect_combinations(patterns, &fewest, &mut vec![], 0, limit, &mut found);
         }
 
-        false
+        found
     }
 }
 
@@ -125,39 +147,95 @@ mod tests {
         let input =
             "uwbr, bbgrb, wwbg, gugurur, rgurw, rbgw, bw, wurbru, bgrurw, gbw, bwgg, ubwg, wrgb, bwg, rwgug, bbgb, grbrbuug, ruw, grrrbu, ubwurbwu, rbg, buu, bru, rwugubg, brgwu, wr, uwb, wgwu, rubgwu, rrw, bwwrb, rrr, uww, gbgwrww, gbb, uugw, wurgr, wgbwbrur, grwbg, gwrbuw, wgu, wrr, ug, rbr, guur, gurru, urgwggw, wuwbwg, bwggrwgw, brurww, wbr, buww, bgrb, bbb, rgr, bgbbwbu, bububub, wbugu, gwgub, gubbwwrb, rrgwuuwu, uggb, rgbg, ubgbrw, rrrg, rr, gbuwb, wwbugwb, rbwbr, wbrrb, rug, b, bwwrwr, gug, bwrw, rbu, r, bgu, uub, bwb, ubwuuw, uuur, wgur, urw, gbbw, uuuru, ur, wburb, gwuuw, rbb, wuu, gru, urb, wb, bbwb, grgugw, rgruuur, rrwbwrg, uubru, ubrw, rbbgrgr, br, wub, ww, guu, gur, uug, bww, ubrwbgu, brwu, wrbgub, ggwwgwb, uuwuugrb, rwgrw, ggurr, gbg, rrwb, gbrbgr, bbruu, rrbrbw, wrurug, ruuw, wg, bwwwwb, bubg, ubr, bwu, uugub, ruuuubb, rggw, urr, urwgr, rww, wwggu, gbbwgw, ubggu, wbb, uugr, uubgu, uwg, rwwuru, uuurb, buuu, wbru, ubwr, urg, urrwrwgb, rbubb, uw, brrg, bgb, gwrguu, bgwu, bubwbg, rb, grb, uugubb, gww, bggbru, gwb, grwwwugb, gwggbg, bbg, ubrg, rgu, gwguu, ruwrwb, gggwb, bbbbug, ruwrurbw, rrbww, rwbww, grru, wwrwwub, guwurb, ugwbg, wgruuwwb, rg, uubgww, ruu, gbbbrbb, ggruu, gwgbwu, buw, uur, uuurbgr, uu, gwwrwgb, brrr, uwuw, guw, rwwr, gwuguub, wgb, brwwr, brub, uurg, ugb, rguub, brwbg, ruuuu, bggbgw, wrgwwwg, rrgw, uurrg, wwuwu, gugg, wwub, wuw, uuu, wugruu, rrgb, uwr, rbrwwb, grr, rrrb, bgg, ruwr, ggrurwr, ugg, bwr, rguwru, wgub, ugr, wrwu, gwbw, bwbur, wgbbr, rrrw, grwrgg, rgb, ugu, wwg, wugur, ggu, ubrwwgbu, uwub, urubwug, ugw, wguub, wbubugw, gugwwu, rwb, ubuu, gbu, wugbg, gbbu, rrg, brbr, urrg, guwub, bbr, wbbuburr, wwburwb, grbugbu, uuwbrw, wubr, wbbggww, ubub, ruru, grwbgbb, bbw, uru, wgg, ubrwu, rw, wurbu, uwrwb, rrb, rugggr, rgrwr, wrw, ggw, grgugu, uwug, rur, wruuw, bugbur, bb, uwggrrb, gbgr, rgbrw, bur, rbw, bub, wrb, rrgr, wrug, gr, uubw, gbr, rguuwbb, buubg, ugrbrwr, gubu, ru, wgr, ubggb, gwuu, rwgru, rwwrwg, grrr, gwu, guuuwww, ggugug, ubwrgbww, rgwbggu, brr, uubbr, wgwbw, ub, rrbru, uwuuur, rgwr, rruw, urgguw, urwb, uggr, bu, brwrw, gggub, uurb, buuw, ggb, wbrbgu, gurb, wuwbu, grg, buubbg, ggrubb, urwbwrb, uwugrgu, rurrw, ubw, wrg, u, www, bgr, wrbgw, wbg, rub, wwu, ubrgwg, bwuw, brb, ggr, bwrb, wggb, ubb, wrrrgw, urubrgwg, bbgr, uwwr, rwg, buwubw, bgw, brg, w, rubb, bwwwbb, ggg, bbbw, gbguw, guuw, grggr, wur, wug, grwwgrw, wbbwu, rru, bgbwgr, brw, gwg, uubwwur, wrwgw, wubwb, ugubrb, burb, ggwrr, rwbg, bwgugw, gbgwgwgr, uwgb, wu, gugww, wwuugrwb, rugu, wgw, rwub, uuwwbg, grrg, uuw, gu, gwgb, uwwur, rwbbbbb, gbur, uwugrwgg, rrgg, buwg, rwr, bwuwrgb, ubu, bgbrguwb, rbru, gw, bbbbg, ubuwuu, bbu, rgg, rwu, wbw, ggwbru, guwb, wwr, wuubg, ubrww, rwruru, urbww, gwrr, wrgwbu, bwbrugu, wwrww, grw, rgw, wwb, wguuwrw, wrgrg, wgug, uwwg, bbubwbru, wrwbu, gub, bwbg, wgrwr, gg, burbrbw, uggu, rgbgbw, gwr, rgrwbw, gguuur, urbw, wwrgw, wrggu, gbug, ubug\n\nwgrggwgruubbgbgurwrbgggwbuwwruburwrbrwwgrubg";
 
-        let (available, combinations) = towel_combinations_from_string(input);
+        let (available, combinations) = towels_from_string(input).unwrap();
+        let patterns = Trie::from_towels(&available);
 
         let result = combinations
             .first()
             .unwrap()
-            .is_combination_possible(&available);
-        assert_eq!(result, false);
+            .is_combination_possible(&patterns);
+        assert!(!result);
     }
 
     #[test]
-    fn filters_available_stripes() {
+    fn keeps_all_available_stripes() {
         let input = "ww, w, wwb\n\nwwbg";
 
-        let (available, combinations) = towel_combinations_from_string(input);
+        let (available, combinations) = towels_from_string(input).unwrap();
 
         assert_eq!(
             available,
-            vec![Towel::from_string("w"), Towel::from_string("wwb")]
+            vec![
+                Towel::from_string("ww").unwrap(),
+                Towel::from_string("w").unwrap(),
+                Towel::from_string("wwb").unwrap()
+            ]
         );
         assert_eq!(combinations.len(), 1);
     }
 
+    #[test]
+    fn counts_combinations_with_all_patterns() {
+        let input = "ww, w, wwb\n\nwwbg\nwww";
+
+        let (available, combinations) = towels_from_string(input).unwrap();
+        let patterns = Trie::from_towels(&available);
+
+        let counts: Vec<u64> = combinations
+            .iter()
+            .map(|towel| towel.count_combinations(&patterns))
+            .collect();
+        assert_eq!(counts, vec![0, 3]);
+
+        let patterns = Trie::from_towels(&available[1..]);
+        assert_eq!(combinations[1].count_combinations(&patterns), 1);
+    }
+
+    #[test]
+    fn finds_concrete_combinations() {
+        let input = "r, wr, b, g, bwu, rb, gb, br\n\nbrwrr\ngbbr\nubwu";
+
+        let (available, combinations) = towels_from_string(input).unwrap();
+        let patterns = Trie::from_towels(&available);
+
+        let towel = combinations[0].find_combination(&patterns).unwrap();
+        assert_eq!(towel.id, vec!["br", "wr", "r"]);
+        assert_eq!(towel.stripes, combinations[0].stripes);
+
+        let towel = combinations[1].find_combination(&patterns).unwrap();
+        assert_eq!(towel.id, vec!["gb", "br"]);
+        assert_eq!(combinations[2].find_combination(&patterns), None);
+
+        let ids: Vec<Vec<String>> = combinations[1]
+            .first_combinations(&patterns, 3)
+            .into_iter()
+            .map(|towel| towel.id)
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                vec!["g", "b", "b", "r"],
+                vec!["g", "b", "br"],
+                vec!["gb", "b", "r"]
+            ]
+        );
+
+        assert_eq!(combinations[1].first_combinations(&patterns, 10).len(), 4);
+        assert!(combinations[2].first_combinations(&patterns, 10).is_empty());
+    }
+
     #[test]
     fn determines_if_short_combination_is_possible() {
         let input = "ww, w, wwb\n\nwwbg";
 
-        let (available, combinations) = towel_combinations_from_string(input);
+        let (available, combinations) = towels_from_string(input).unwrap();
+        let patterns = Trie::from_towels(&available);
 
         let result = combinations
             .first()
             .unwrap()
-            .is_combination_possible(&available);
+            .is_combination_possible(&patterns);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 }
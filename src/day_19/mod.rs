@@ -1,29 +1,71 @@
 mod combine;
 mod towel;
+mod trie;
 
-use crate::util::read_input;
-use combine::towel_combinations_from_string;
+use crate::util::Error;
+use combine::towels_from_string;
+use towel::Towel;
+use trie::Trie;
 
-fn find_towel_combinations(input: &str) -> usize {
-    let (available, combinations) = towel_combinations_from_string(input);
+const EXPLAINED_ARRANGEMENTS: usize = 3;
 
-    combinations
+fn find_towel_combinations(input: &str) -> Result<usize, Error> {
+    let (available, combinations) = towels_from_string(input)?;
+    let patterns = Trie::from_towels(&available);
+
+    Ok(combinations
         .iter()
-        .filter(|towel| towel.is_combination_possible(&available))
-        .count()
+        .filter(|towel| towel.is_combination_possible(&patterns))
+        .count())
+}
+
+pub fn solve_part_1(input: &str) -> Result<usize, Error> {
+    find_towel_combinations(input)
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_19/input.txt");
+fn count_towel_arrangements(input: &str) -> Result<u64, Error> {
+    let (available, combinations) = towels_from_string(input)?;
+    let patterns = Trie::from_towels(&available);
+
+    Ok(combinations
+        .iter()
+        .map(|towel| towel.count_combinations(&patterns))
+        .sum())
+}
 
-    // TODO: Optimise
-    find_towel_combinations(&input)
+pub fn solve_part_2(input: &str) -> Result<u64, Error> {
+    count_towel_arrangements(input)
 }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_19/input.txt");
+fn explain_towel(towel: &Towel, patterns: &Trie) -> String {
+    let design: String = towel.stripes.iter().collect();
 
-    0
+    let Some(fewest) = towel.find_combination(patterns) else {
+        return format!("{design}: impossible");
+    };
+
+    let first: Vec<String> = towel
+        .first_combinations(patterns, EXPLAINED_ARRANGEMENTS)
+        .iter()
+        .map(|arrangement| arrangement.id.join(" "))
+        .collect();
+
+    format!(
+        "{design}: {} (first: {})",
+        fewest.id.join(" "),
+        first.join(" | ")
+    )
+}
+
+pub fn explain(input: &str) -> Result<String, Error> {
+    let (available, combinations) = towels_from_string(input)?;
+    let patterns = Trie::from_towels(&available);
+
+    Ok(combinations
+        .iter()
+        .map(|towel| explain_towel(towel, &patterns))
+        .collect::<Vec<String>>()
+        .join("\n"))
 }
 
 #[cfg(test)]
@@ -35,7 +77,27 @@ mod tests {
         let input =
             "r, wr, b, g, bwu, rb, gb, br\n\nbrwrr\nbggr\ngbbr\nrrbgbr\nubwu\nbwurrg\nbrgr\nbbrgwb";
 
-        let result = find_towel_combinations(input);
+        let result = find_towel_combinations(input).unwrap();
         assert_eq!(result, 6);
     }
+
+    #[test]
+    fn counts_towel_arrangements() {
+        let input =
+            "r, wr, b, g, bwu, rb, gb, br\n\nbrwrr\nbggr\ngbbr\nrrbgbr\nubwu\nbwurrg\nbrgr\nbbrgwb";
+
+        let result = count_towel_arrangements(input).unwrap();
+        assert_eq!(result, 16);
+    }
+
+    #[test]
+    fn explains_towel_arrangements() {
+        let input = "r, wr, b, g, bwu, rb, gb, br\n\nbrwrr\ngbbr\nubwu";
+
+        let result = explain(input).unwrap();
+        assert_eq!(
+            result,
+            "brwrr: br wr r (first: b r wr r | br wr r)\ngbbr: gb br (first: g b b r | g b br | gb b r)\nubwu: impossible"
+        );
+    }
 }
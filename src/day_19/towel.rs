@@ -1,4 +1,5 @@
-use std::{cmp::Ordering, fmt::Debug};
+use crate::util::Error;
+use std::fmt::Debug;
 
 #[derive(Hash, Clone, PartialEq, Eq)]
 pub struct Towel {
@@ -18,44 +19,19 @@ impl Debug for Towel {
     }
 }
 
-impl PartialOrd for Towel {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.stripes.len().cmp(&other.stripes.len()))
-    }
-}
-
-impl Ord for Towel {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
-    }
-}
-
 impl Towel {
-    pub fn from_string(input: &str) -> Self {
-        Self {
-            stripes: input.chars().collect(),
-            id: vec![input.to_string()],
+    pub fn from_string(input: &str) -> Result<Self, Error> {
+        if input.is_empty() || !input.chars().all(|stripe| "wubrg".contains(stripe)) {
+            return Err(Error::parse(input, "stripes of 'w', 'u', 'b', 'r' or 'g'"));
         }
-    }
-
-    pub fn many_from_string(input: &str, delimiter: &str) -> Vec<Towel> {
-        input
-            .split(delimiter)
-            .map(|segment| Self::from_string(segment))
-            .collect()
-    }
-}
 
-impl Towel {
-    pub fn copy(&self, other: Towel) -> Self {
-        let mut copy = self.clone();
-        copy.stripes.extend(other.stripes);
-        copy.id.extend(other.id);
-
-        copy
+        Ok(Self {
+            stripes: input.chars().collect(),
+            id: vec![input.to_string()],
+        })
     }
 
-    pub fn format_id(&self) -> String {
-        self.id.clone().join("")
+    pub fn many_from_string(input: &str, delimiter: &str) -> Result<Vec<Towel>, Error> {
+        input.split(delimiter).map(Self::from_string).collect()
     }
 }
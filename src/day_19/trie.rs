@@ -0,0 +1,84 @@
+use super::towel::Towel;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+struct Node {
+    children: HashMap<char, usize>,
+    terminal: bool,
+}
+
+#[derive(Debug)]
+pub struct Trie {
+    nodes: Vec<Node>,
+}
+
+impl Trie {
+    pub fn from_towels(towels: &[Towel]) -> Self {
+        let mut trie = Self {
+            nodes: vec![Node::default()],
+        };
+
+        for towel in towels {
+            trie.insert(&towel.stripes);
+        }
+
+        trie
+    }
+
+    fn insert(&mut self, stripes: &[char]) {
+        let mut node = 0;
+
+        for stripe in stripes {
+            node = match self.nodes[node].children.get(stripe) {
+                Some(child) => *child,
+                None => {
+                    self.nodes.push(Node::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(*stripe, child);
+
+                    child
+                }
+            };
+        }
+
+        self.nodes[node].terminal = true;
+    }
+
+    pub fn prefix_lengths<'a>(&'a self, stripes: &'a [char]) -> impl Iterator<Item = usize> + 'a {
+        let mut node = 0;
+
+        stripes
+            .iter()
+            .map_while(move |stripe| {
+                node = *self.nodes[node].children.get(stripe)?;
+                Some(self.nodes[node].terminal)
+            })
+            .enumerate()
+            .filter_map(|(index, terminal)| match terminal {
+                true => Some(index + 1),
+                false => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_patterns_at_start_of_stripes() {
+        let towels = Towel::many_from_string("r, wr, b, g, bwu, rb, gb, br", ", ").unwrap();
+        let trie = Trie::from_towels(&towels);
+
+        let stripes: Vec<char> = "brwrr".chars().collect();
+        let lengths: Vec<usize> = trie.prefix_lengths(&stripes).collect();
+        assert_eq!(lengths, vec![1, 2]);
+
+        let stripes: Vec<char> = "bwurrg".chars().collect();
+        let lengths: Vec<usize> = trie.prefix_lengths(&stripes).collect();
+        assert_eq!(lengths, vec![1, 3]);
+
+        let stripes: Vec<char> = "ubwu".chars().collect();
+        assert_eq!(trie.prefix_lengths(&stripes).count(), 0);
+    }
+}
@@ -1,10 +1,5 @@
-use crate::util::{move_steps_in_direction, Direction, Position};
-use std::{
-    collections::{BinaryHeap, HashMap},
-    isize,
-};
-
-use super::path::Path;
+use crate::util::{bfs, manhattan_distance, Direction, Error, Grid, Position, Route};
+use std::collections::VecDeque;
 
 #[derive(Debug, PartialEq, Clone)]
 enum MapFeature {
@@ -12,167 +7,126 @@ enum MapFeature {
     Wall,
 }
 
-#[derive(Clone)]
+#[derive(Debug, PartialEq)]
+pub struct Cheat {
+    pub start: Position,
+    pub end: Position,
+    pub saved: usize,
+}
+
 pub struct Map2D {
-    tiles: HashMap<Position, MapFeature>,
+    tiles: Grid<MapFeature>,
     start: Position,
     end: Position,
-    size: Position,
+
+    from_start: Grid<Option<usize>>,
+    to_end: Grid<Option<usize>>,
 }
 
 impl Map2D {
-    pub fn from_string(text: &str) -> Self {
-        let mut tiles = HashMap::new();
-        let mut start = (0, 0);
-        let mut end = (0, 0);
-
-        text.lines().enumerate().for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, character)| {
-                let x = x as isize;
-                let y = y as isize;
-                let position = (x, y);
-
-                let feature = match character {
-                    '#' => MapFeature::Wall,
-                    'S' => {
-                        start = position;
-                        MapFeature::Empty
-                    }
-                    'E' => {
-                        end = position;
-                        MapFeature::Empty
-                    }
-                    _ => MapFeature::Empty,
-                };
-
-                tiles.insert(position, feature);
-            });
+    pub fn from_string(text: &str) -> Result<Self, Error> {
+        let characters = Grid::from_characters(text, ".#SE")?;
+
+        let start = characters.find(&'S').unwrap_or_default();
+        let end = characters.find(&'E').unwrap_or_default();
+        let tiles = characters.map(|character| match character {
+            '#' => MapFeature::Wall,
+            _ => MapFeature::Empty,
         });
 
-        let size_y = text.lines().count();
-        let size_x = text.lines().nth(0).unwrap().chars().count();
-
-        Self {
+        Ok(Self {
+            from_start: distances_from(&tiles, &start),
+            to_end: distances_from(&tiles, &end),
             tiles,
             start,
             end,
-            size: (size_x as isize, size_y as isize),
-        }
+        })
     }
 }
 
-fn construct_path(
-    path_map: &HashMap<Position, Position>,
-    start: (isize, isize),
-    end: (isize, isize),
-) -> Vec<Position> {
-    let mut result = vec![];
-
-    let mut next = &end;
-
-    while next != &start {
-        result.push(next.clone());
-        next = path_map.get(next).unwrap();
-    }
-
-    result
+fn possible_steps_from(tiles: &Grid<MapFeature>, position: &Position) -> Vec<Position> {
+    tiles
+        .neighbours(
+            position,
+            [Direction::E, Direction::S, Direction::W, Direction::N],
+        )
+        .filter_map(|(next, feature)| match feature {
+            MapFeature::Empty => Some(next),
+            _ => None,
+        })
+        .collect()
 }
 
-fn positions_form_line(reference: &Position, positions: &Vec<Position>) -> bool {
-    let (x, y) = reference;
+fn distances_from(tiles: &Grid<MapFeature>, origin: &Position) -> Grid<Option<usize>> {
+    let mut distances = tiles.map(|_| None);
+    let mut queue = VecDeque::from([(*origin, 0)]);
+    distances.set(origin, Some(0));
 
-    let line_x = positions.iter().all(|position| &position.0 == x);
-    let line_y = positions.iter().all(|position| &position.1 == y);
+    while let Some((position, distance)) = queue.pop_front() {
+        for next in possible_steps_from(tiles, &position) {
+            if let Some(None) = distances.get(&next) {
+                distances.set(&next, Some(distance + 1));
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
 
-    line_x || line_y
+    distances
 }
 
 impl Map2D {
-    pub fn possible_cheats(&self) -> Vec<Position> {
-        self.tiles
-            .iter()
-            .filter_map(|(position, feature)| match feature {
-                MapFeature::Empty => return None,
-                _ => {
-                    let (x, y) = position;
-                    let (x_end, y_end) = &self.size;
-
-                    if x == &0 || y == &0 || x == x_end || y == y_end {
-                        return None;
-                    }
-
-                    let neighbors = self.possible_steps_from(&position);
-
-                    match neighbors.len() >= 2 {
-                        true => Some(*position),
-                        _ => None,
-                    }
-                }
-            })
-            .collect()
+    pub fn best_route(&self) -> Option<Route> {
+        bfs(
+            self.start,
+            |position| possible_steps_from(&self.tiles, position),
+            |position| position == &self.end,
+        )
+        .map(Route::from)
     }
 
-    fn remove_wall(&mut self, position: &Position) {
-        self.tiles.insert(*position, MapFeature::Empty);
+    pub fn path_length(&self) -> Option<usize> {
+        self.best_route().map(|route| route.cost())
     }
 
-    pub fn path_length_with_cheat(&self, cheat: &Position) -> usize {
-        let mut map = self.clone();
-        map.remove_wall(&cheat);
+    pub fn path_length_with_cheat(&self, start: &Position, end: &Position) -> Option<usize> {
+        let before = (*self.from_start.get(start)?)?;
+        let after = (*self.to_end.get(end)?)?;
 
-        map.best_path().unwrap().len()
+        Some(before + manhattan_distance(start, end) + after)
     }
 
-    fn possible_steps_from(&self, position: &Position) -> Vec<Position> {
-        [Direction::E, Direction::S, Direction::W, Direction::N]
-            .iter()
-            .filter_map(|direction| {
-                let next = move_steps_in_direction(position, 1, direction);
-                match self.tiles.get(&next) {
-                    None => None,
-                    Some(feature) => match feature {
-                        MapFeature::Empty => Some(next),
-                        _ => None,
-                    },
-                }
-            })
-            .collect()
-    }
-
-    pub fn best_path(&self) -> Option<Vec<Position>> {
-        let Map2D { start, end, .. } = *self;
-
-        let mut distances: HashMap<Position, isize> = HashMap::from([(start, 0)]);
-        let mut came_from: HashMap<Position, Position> = HashMap::new();
-        let mut queue: BinaryHeap<Path> = BinaryHeap::from([Path::new(start, 0)]);
+    pub fn cheats_within(&self, max_len: usize) -> Vec<Cheat> {
+        let Some(path_base) = self.path_length() else {
+            return vec![];
+        };
 
-        distances.insert(start, 0);
+        let reach = max_len as isize;
+        let mut cheats = vec![];
 
-        while let Some(path) = queue.pop() {
-            let Path { position, .. } = path;
-
-            for neighbor in self.possible_steps_from(&position) {
-                if neighbor == end {
-                    came_from.insert(end, position);
+        for (start, distance) in self.from_start.iter() {
+            if distance.is_none() {
+                continue;
+            }
 
-                    let path = construct_path(&came_from, start, end);
-                    return Some(path);
-                }
+            for dy in -reach..=reach {
+                let remaining = reach - dy.abs();
 
-                let new_distance = distances.get(&position).unwrap() + 1;
-                let distance = distances.get(&neighbor).unwrap_or(&isize::MAX);
+                for dx in -remaining..=remaining {
+                    let end = (start.0 + dx, start.1 + dy);
 
-                if &new_distance >= distance {
-                    continue;
+                    match self.path_length_with_cheat(&start, &end) {
+                        Some(length) if length < path_base => cheats.push(Cheat {
+                            start,
+                            end,
+                            saved: path_base - length,
+                        }),
+                        _ => {}
+                    }
                 }
-
-                distances.insert(neighbor, new_distance);
-                came_from.insert(neighbor, position);
-                queue.push(Path::new(neighbor, new_distance));
             }
         }
 
-        None
+        cheats
     }
 }
 
@@ -183,18 +137,58 @@ mod tests {
     #[test]
     fn finds_path_length_with_cheat() {
         let input = "###############\n#...#...#.....#\n#.#.#.#.#.###.#\n#S#...#.#.#...#\n#######.#.#.###\n#######.#.#...#\n#######.#.###.#\n###..E#...#...#\n###.#######.###\n#...###...#...#\n#.#####.#.###.#\n#.#...#.#.#...#\n#.#.#.#.#.#.###\n#...#...#...###\n###############";
-        let map = Map2D::from_string(input);
+        let map = Map2D::from_string(input).unwrap();
 
-        let result = map.path_length_with_cheat(&(8, 1));
-        assert_eq!(result, 72);
+        assert_eq!(map.path_length(), Some(84));
 
-        let result = map.path_length_with_cheat(&(10, 7));
-        assert_eq!(result, 64);
+        let route = map.best_route().unwrap();
+        assert_eq!(route.cost(), 84);
+        assert_eq!(route.tiles().first(), Some(&(1, 3)));
+        assert_eq!(route.tiles().last(), Some(&(5, 7)));
+        assert!(route
+            .steps()
+            .all(|(from, to)| manhattan_distance(&from, &to) == 1));
 
-        let result = map.path_length_with_cheat(&(8, 8));
-        assert_eq!(result, 46);
+        let result = map.path_length_with_cheat(&(7, 1), &(9, 1));
+        assert_eq!(result, Some(72));
 
-        let result = map.path_length_with_cheat(&(6, 7));
-        assert_eq!(result, 20);
+        let result = map.path_length_with_cheat(&(9, 7), &(11, 7));
+        assert_eq!(result, Some(64));
+
+        let result = map.path_length_with_cheat(&(8, 7), &(8, 9));
+        assert_eq!(result, Some(46));
+
+        let result = map.path_length_with_cheat(&(7, 7), &(5, 7));
+        assert_eq!(result, Some(20));
+
+        let result = map.path_length_with_cheat(&(7, 7), &(6, 7));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn handles_unreachable_routes() {
+        let map = Map2D::from_string("#####\n#S#E#\n#####").unwrap();
+        assert_eq!(map.best_route(), None);
+        assert_eq!(map.path_length(), None);
+        assert!(map.cheats_within(2).is_empty());
+    }
+
+    #[test]
+    fn finds_cheats_within_length() {
+        let input = "###############\n#...#...#.....#\n#.#.#.#.#.###.#\n#S#...#.#.#...#\n#######.#.#.###\n#######.#.#...#\n#######.#.###.#\n###..E#...#...#\n###.#######.###\n#...###...#...#\n#.#####.#.###.#\n#.#...#.#.#...#\n#.#.#.#.#.#.###\n#...#...#...###\n###############";
+        let map = Map2D::from_string(input).unwrap();
+
+        let cheats = map.cheats_within(2);
+        assert_eq!(cheats.len(), 44);
+        assert!(cheats.contains(&Cheat {
+            start: (7, 7),
+            end: (5, 7),
+            saved: 64,
+        }));
+
+        let cheats = map.cheats_within(20);
+        let count = |saved: usize| cheats.iter().filter(|cheat| cheat.saved == saved).count();
+        assert_eq!(count(50), 32);
+        assert_eq!(count(76), 3);
     }
 }
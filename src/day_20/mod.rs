@@ -1,50 +1,30 @@
 mod map_2d;
-mod path;
 
-use crate::util::{read_input, Position};
-use map_2d::Map2D;
+use crate::util::Error;
+use map_2d::{Cheat, Map2D};
 
-fn find_best_cheats(input: &str, minimum_saved: usize) -> usize {
-    find_cheats(input)
-        .iter()
-        .filter(|(position, saved)| {
-            println!("cheat at {:?} saves {}", position, saved);
-            saved >= &minimum_saved
-        })
-        .count()
+fn find_cheats(input: &str, max_len: usize) -> Result<Vec<Cheat>, Error> {
+    let map = Map2D::from_string(input)?;
+    Ok(map.cheats_within(max_len))
 }
 
-fn find_cheats(input: &str) -> Vec<(Position, usize)> {
-    let map = Map2D::from_string(input);
-    let path_base = map.best_path().unwrap().len();
-
-    let cheats = map.possible_cheats();
-
-    cheats
+fn count_cheats_within(input: &str, max_len: usize, minimum_saved: usize) -> Result<usize, Error> {
+    Ok(find_cheats(input, max_len)?
         .iter()
-        .enumerate()
-        .filter_map(|(i, position)| {
-            println!("{} / {}", i, cheats.len());
-            let length = map.path_length_with_cheat(&position);
-            match length < path_base {
-                true => Some((*position, path_base - length)),
-                _ => None,
-            }
-        })
-        .collect()
+        .filter(|cheat| cheat.saved >= minimum_saved)
+        .count())
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_20/input.txt");
-
-    // TODO: Optimise
-    find_best_cheats(&input, 100)
+fn find_best_cheats(input: &str, minimum_saved: usize) -> Result<usize, Error> {
+    count_cheats_within(input, 2, minimum_saved)
 }
 
-pub fn solve_part_2() -> usize {
-    let _input = read_input("src/day_20/input.txt");
+pub fn solve_part_1(input: &str) -> Result<usize, Error> {
+    find_best_cheats(input, 100)
+}
 
-    0
+pub fn solve_part_2(input: &str) -> Result<usize, Error> {
+    count_cheats_within(input, 20, 100)
 }
 
 #[cfg(test)]
@@ -57,16 +37,14 @@ mod tests {
     fn finds_cheats_in_map() {
         let input = "###############\n#...#...#.....#\n#.#.#.#.#.###.#\n#S#...#.#.#...#\n#######.#.#.###\n#######.#.#...#\n#######.#.###.#\n###..E#...#...#\n###.#######.###\n#...###...#...#\n#.#####.#.###.#\n#.#...#.#.#...#\n#.#.#.#.#.#.###\n#...#...#...###\n###############";
 
-        let result = find_cheats(input);
+        let result = find_cheats(input, 2).unwrap();
 
-        let grouped = result
-            .iter()
-            .fold(HashMap::new(), |mut result, (_, saved)| {
-                let current = result.get(saved).unwrap_or(&0);
-                result.insert(*saved, current + 1);
+        let grouped = result.iter().fold(HashMap::new(), |mut result, cheat| {
+            let current = result.get(&cheat.saved).unwrap_or(&0);
+            result.insert(cheat.saved, current + 1);
 
-                result
-            });
+            result
+        });
 
         assert_eq!(
             grouped,
@@ -90,7 +68,16 @@ mod tests {
     fn finds_cheats_with_minimum_saving() {
         let input = "###############\n#...#...#.....#\n#.#.#.#.#.###.#\n#S#...#.#.#...#\n#######.#.#.###\n#######.#.#...#\n#######.#.###.#\n###..E#...#...#\n###.#######.###\n#...###...#...#\n#.#####.#.###.#\n#.#...#.#.#...#\n#.#.#.#.#.#.###\n#...#...#...###\n###############";
 
-        let result = find_best_cheats(input, 20);
+        let result = find_best_cheats(input, 20).unwrap();
         assert_eq!(result, 5);
     }
+
+    #[test]
+    fn counts_cheats_within_length() {
+        let input = "###############\n#...#...#.....#\n#.#.#.#.#.###.#\n#S#...#.#.#...#\n#######.#.#.###\n#######.#.#...#\n#######.#.###.#\n###..E#...#...#\n###.#######.###\n#...###...#...#\n#.#####.#.###.#\n#.#...#.#.#...#\n#.#.#.#.#.#.###\n#...#...#...###\n###############";
+
+        assert_eq!(count_cheats_within(input, 2, 20).unwrap(), 5);
+        assert_eq!(count_cheats_within(input, 20, 50).unwrap(), 285);
+        assert_eq!(count_cheats_within(input, 20, 76).unwrap(), 3);
+    }
 }
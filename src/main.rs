@@ -1,3 +1,4 @@
+mod cli;
 mod day_01;
 mod day_02;
 mod day_03;
@@ -18,106 +19,224 @@ mod day_17;
 mod day_18;
 mod day_19;
 mod day_20;
+mod solver;
 mod util;
 
-use std::io::stdin;
+use cli::{Arguments, Command, DaySelection, InputSource, Part, USAGE};
+use solver::{Answer, ExpectedAnswers, Registry, Solution, Verdict};
+use std::{env, io::stdin, process::ExitCode};
+use util::{read_input, read_stdin, Error};
 
-fn main() {
-    println!("solve for day: ");
-    let mut buffer = String::new();
-    stdin().read_line(&mut buffer).unwrap();
+fn registry() -> Registry {
+    let mut registry = Registry::default();
+
+    registry.register(1, Solution(day_01::solve_part_1, day_01::solve_part_2));
+    registry.register(2, Solution(day_02::solve_part_1, day_02::solve_part_2));
+    registry.register(3, Solution(day_03::solve_part_1, day_03::solve_part_2));
+    registry.register(4, Solution(day_04::solve_part_1, day_04::solve_part_2));
+    registry.register(5, Solution(day_05::solve_part_1, day_05::solve_part_2));
+    registry.register(6, Solution(day_06::solve_part_1, day_06::solve_part_2));
+    registry.register(7, Solution(day_07::solve_part_1, day_07::solve_part_2));
+    registry.register(8, Solution(day_08::solve_part_1, day_08::solve_part_2));
+    registry.register(9, Solution(day_09::solve_part_1, day_09::solve_part_2));
+    registry.register(10, Solution(day_10::solve_part_1, day_10::solve_part_2));
+    registry.register(11, Solution(day_11::solve_part_1, day_11::solve_part_2));
+    registry.register(12, Solution(day_12::solve_part_1, day_12::solve_part_2));
+    registry.register(13, Solution(day_13::solve_part_1, day_13::solve_part_2));
+    registry.register(14, Solution(day_14::solve_part_1, day_14::solve_part_2));
+    registry.register(15, Solution(day_15::solve_part_1, day_15::solve_part_2));
+    registry.register(16, Solution(day_16::solve_part_1, day_16::solve_part_2));
+    registry.register(17, Solution(day_17::solve_part_1, day_17::solve_part_2));
+    registry.register(18, Solution(day_18::solve_part_1, day_18::solve_part_2));
+    registry.register(19, Solution(day_19::solve_part_1, day_19::solve_part_2));
+    registry.register(20, Solution(day_20::solve_part_1, day_20::solve_part_2));
+
+    registry.register_explainer(17, day_17::explain);
+    registry.register_explainer(18, day_18::explain);
+    registry.register_explainer(19, day_19::explain);
+
+    registry
+}
+
+fn default_input_path(day: u8) -> String {
+    format!("src/day_{day:02}/input.txt")
+}
+
+fn load_input(day: u8, source: &InputSource) -> Result<String, String> {
+    let input = match source {
+        InputSource::Default => read_input(&default_input_path(day)),
+        InputSource::File(path) => read_input(path),
+        InputSource::Stdin => read_stdin(),
+    };
+
+    input.map_err(|error| format!("day {day}: {error}"))
+}
+
+fn load_expected_answers(path: &str) -> Result<ExpectedAnswers, String> {
+    let text = read_input(path).map_err(|error| error.to_string())?;
+
+    ExpectedAnswers::from_string(&text).map_err(|error| format!("{path}: {error}"))
+}
+
+fn report_answer(
+    day: u8,
+    part: u8,
+    answer: Result<Answer, Error>,
+    expected: Option<&ExpectedAnswers>,
+) -> bool {
+    let answer = match answer {
+        Ok(answer) => answer,
+        Err(error) => {
+            eprintln!("day {day}: part {part}: {error}");
+            return false;
+        }
+    };
+
+    let Some(expected) = expected else {
+        println!("part {part}: {answer}");
+        return true;
+    };
+
+    let verdict = expected.verify(day, part, &answer);
+    println!("part {part}: {answer} ({verdict})");
 
-    let day = buffer.trim().parse().expect("type a valid number");
+    !matches!(verdict, Verdict::Fail(_))
+}
+
+fn solve_day(
+    registry: &Registry,
+    expected: Option<&ExpectedAnswers>,
+    day: u8,
+    part: Part,
+    source: &InputSource,
+    explain: bool,
+) -> Result<(), String> {
+    let solver = registry
+        .get(day)
+        .ok_or(format!("day {day}: not yet solved"))?;
+    let input = load_input(day, source)?;
 
     println!("day {day}");
-    match day {
-        1 => {
-            println!("part 1: {}", day_01::solve_part_1());
-            println!("part 2: {}", day_01::solve_part_2());
-        }
-        2 => {
-            println!("part 1: {}", day_02::solve_part_1());
-            println!("part 2: {}", day_02::solve_part_2());
-        }
-        3 => {
-            println!("part 1: {}", day_03::solve_part_1());
-            println!("part 2: {}", day_03::solve_part_2());
-        }
-        4 => {
-            println!("part 1: {}", day_04::solve_part_1());
-            println!("part 2: {}", day_04::solve_part_2());
-        }
-        5 => {
-            println!("part 1: {}", day_05::solve_part_1());
-            println!("part 2: {}", day_05::solve_part_2());
-        }
-        6 => {
-            println!("part 1: {}", day_06::solve_part_1());
-            println!("part 2: {}", day_06::solve_part_2());
-        }
-        7 => {
-            println!("part 1: {}", day_07::solve_part_1());
-            println!("part 2: {}", day_07::solve_part_2());
-        }
-        8 => {
-            println!("part 1: {}", day_08::solve_part_1());
-            println!("part 2: {}", day_08::solve_part_2());
-        }
-        9 => {
-            let result = day_09::solve_part_1();
-            assert_eq!(result, 6242766523059);
-            println!("part 1: {}", result);
-
-            let result = day_09::solve_part_2();
-            assert_eq!(result, 6272188244509);
-            println!("part 2: {}", result);
-        }
-        10 => {
-            println!("part 1: {}", day_10::solve_part_1());
-            println!("part 2: {}", day_10::solve_part_2());
-        }
-        11 => {
-            println!("part 1: {}", day_11::solve_part_1());
-            println!("part 2: {}", day_11::solve_part_2());
-        }
-        12 => {
-            println!("part 1: {}", day_12::solve_part_1());
-            println!("part 2: {}", day_12::solve_part_2());
-        }
-        13 => {
-            println!("part 1: {}", day_13::solve_part_1());
-            println!("part 2: {}", day_13::solve_part_2());
-        }
-        14 => {
-            println!("part 1: {}", day_14::solve_part_1());
-            println!("part 2: {}", day_14::solve_part_2());
-        }
-        15 => {
-            println!("part 1: {}", day_15::solve_part_1());
-            println!("part 2: {}", day_15::solve_part_2());
-        }
-        16 => {
-            println!("part 1: {}", day_16::solve_part_1());
-            println!("part 2: {}", day_16::solve_part_2());
-        }
-        17 => {
-            println!("part 1: {}", day_17::solve_part_1());
-            println!("part 2: {}", day_17::solve_part_2());
+
+    let mut passed = true;
+
+    if part.includes_part_1() {
+        passed &= report_answer(day, 1, solver.solve_part_1(&input), expected);
+    }
+
+    if part.includes_part_2() {
+        passed &= report_answer(day, 2, solver.solve_part_2(&input), expected);
+    }
+
+    if let Some(explainer) = registry.explainer(day).filter(|_| explain) {
+        match explainer(&input) {
+            Ok(explanation) => println!("{explanation}"),
+            Err(error) => {
+                eprintln!("day {day}: explain: {error}");
+                passed = false;
+            }
         }
-        18 => {
-            println!("part 1: {}", day_18::solve_part_1());
-            println!("part 2: {:?}", day_18::solve_part_2());
+    }
+
+    match passed {
+        true => Ok(()),
+        false => Err(format!("day {day}: failed")),
+    }
+}
+
+fn prompt_for_day() -> Result<Command, String> {
+    println!("solve for day: ");
+    let mut buffer = String::new();
+    stdin()
+        .read_line(&mut buffer)
+        .map_err(|error| error.to_string())?;
+
+    let day = buffer
+        .trim()
+        .parse()
+        .map_err(|_| "type a valid number".to_string())?;
+
+    Ok(Command::Solve(Arguments::for_day(day)))
+}
+
+fn run(arguments: Arguments) -> ExitCode {
+    let Arguments {
+        days,
+        part,
+        input,
+        verify,
+        explain,
+        answers,
+    } = arguments;
+    let registry = registry();
+
+    let expected = match verify {
+        true => match load_expected_answers(&answers) {
+            Ok(expected) => Some(expected),
+            Err(message) => {
+                eprintln!("{message}");
+                return ExitCode::FAILURE;
+            }
+        },
+        false => None,
+    };
+
+    let days = match days {
+        DaySelection::Single(day) => vec![day],
+        DaySelection::All => registry.days().collect(),
+    };
+
+    let mut failed = false;
+
+    for day in days {
+        if let Err(message) = solve_day(&registry, expected.as_ref(), day, part, &input, explain) {
+            eprintln!("{message}");
+            failed = true;
         }
-        19 => {
-            println!("part 1: {}", day_19::solve_part_1());
-            println!("part 2: {}", day_19::solve_part_2());
+    }
+
+    match failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+fn assemble(source: InputSource) -> ExitCode {
+    let text = match source {
+        InputSource::File(path) => read_input(&path),
+        _ => read_stdin(),
+    };
+
+    match text.and_then(|text| day_17::assemble(&text)) {
+        Ok(program) => {
+            println!("{program}");
+            ExitCode::SUCCESS
         }
-        20 => {
-            println!("part 1: {}", day_20::solve_part_1());
-            println!("part 2: {:?}", day_20::solve_part_2());
+        Err(error) => {
+            eprintln!("assemble: {error}");
+            ExitCode::FAILURE
         }
-        _ => {
-            println!("not yet solved");
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match args.is_empty() {
+        true => prompt_for_day(),
+        false => Command::from_args(args),
+    };
+
+    match command {
+        Ok(Command::Help) => {
+            println!("{USAGE}");
+            ExitCode::SUCCESS
+        }
+        Ok(Command::Solve(arguments)) => run(arguments),
+        Ok(Command::Assemble(source)) => assemble(source),
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            ExitCode::from(2)
         }
     }
 }
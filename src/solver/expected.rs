@@ -0,0 +1,123 @@
+use super::Answer;
+use std::{collections::HashMap, fmt::Display};
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail(expected) => write!(f, "fail, expected {expected}"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers {
+    answers: HashMap<(u8, u8), String>,
+}
+
+fn parse_section(line: &str) -> Option<u8> {
+    line.strip_prefix("[day_")?.strip_suffix(']')?.parse().ok()
+}
+
+fn parse_entry(line: &str) -> Option<(u8, String)> {
+    let (key, value) = line.split_once('=')?;
+    let part = key.trim().strip_prefix("part_")?.parse().ok()?;
+
+    let value = value.trim();
+    let value = match value.strip_prefix('"') {
+        Some(quoted) => quoted.strip_suffix('"')?,
+        None => value,
+    };
+
+    Some((part, value.to_string()))
+}
+
+impl ExpectedAnswers {
+    pub fn from_string(input: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+        let mut day: Option<u8> = None;
+
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('[') {
+                day = Some(parse_section(line).ok_or(format!(
+                    "line {}: expected [day_<n>], got '{line}'",
+                    index + 1
+                ))?);
+                continue;
+            }
+
+            let day = day.ok_or(format!(
+                "line {}: answer outside of a [day_<n>] section",
+                index + 1
+            ))?;
+            let (part, value) = parse_entry(line).ok_or(format!(
+                "line {}: expected part_<n> = <answer>, got '{line}'",
+                index + 1
+            ))?;
+
+            answers.insert((day, part), value);
+        }
+
+        Ok(Self { answers })
+    }
+}
+
+impl ExpectedAnswers {
+    pub fn verify(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.answers.get(&(day, part)) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == &answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifies_answers() {
+        let input = "# answers\n[day_09]\npart_1 = 6242766523059\n\n[day_17]\npart_1 = \"3,1,4\"\n";
+        let expected = ExpectedAnswers::from_string(input).unwrap();
+
+        assert_eq!(
+            expected.verify(9, 1, &Answer::Unsigned(6242766523059)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            expected.verify(9, 1, &Answer::Unsigned(1)),
+            Verdict::Fail("6242766523059".to_string())
+        );
+        assert_eq!(
+            expected.verify(9, 2, &Answer::Unsigned(1)),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            expected.verify(17, 1, &Answer::Text("3,1,4".to_string())),
+            Verdict::Pass
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_answers() {
+        assert!(ExpectedAnswers::from_string("part_1 = 3").is_err());
+        assert!(ExpectedAnswers::from_string("[day_x]\npart_1 = 3").is_err());
+        assert!(ExpectedAnswers::from_string("[day_1]\npart 1: 3").is_err());
+        assert!(ExpectedAnswers::from_string("[day_1]\npart_1 = \"3").is_err());
+    }
+}
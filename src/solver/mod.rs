@@ -0,0 +1,79 @@
+mod answer;
+mod expected;
+mod registry;
+
+pub use answer::Answer;
+pub use expected::{ExpectedAnswers, Verdict};
+pub use registry::Registry;
+
+use crate::util::Error;
+
+pub trait Solver {
+    fn solve_part_1(&self, input: &str) -> Result<Answer, Error>;
+
+    fn solve_part_2(&self, input: &str) -> Result<Answer, Error>;
+}
+
+pub struct Solution<A, B>(
+    pub fn(&str) -> Result<A, Error>,
+    pub fn(&str) -> Result<B, Error>,
+);
+
+impl<A: Into<Answer>, B: Into<Answer>> Solver for Solution<A, B> {
+    fn solve_part_1(&self, input: &str) -> Result<Answer, Error> {
+        (self.0)(input).map(Into::into)
+    }
+
+    fn solve_part_2(&self, input: &str) -> Result<Answer, Error> {
+        (self.1)(input).map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_results_to_answers() {
+        assert_eq!(Answer::from(42_usize).to_string(), "42");
+        assert_eq!(Answer::from(-7_i64).to_string(), "-7");
+        assert_eq!(Answer::from("4,6,3".to_string()).to_string(), "4,6,3");
+        assert_eq!(Answer::from(Some(6_u64)), Answer::Unsigned(6));
+        assert_eq!(Answer::from(None::<String>), Answer::Empty);
+    }
+
+    #[test]
+    fn registers_solvers_by_day() {
+        let mut registry = Registry::default();
+        registry.register(2, Solution(|input| Ok(input.len()), |_| Ok(0_i64)));
+        registry.register(
+            1,
+            Solution(|input| Ok(input.to_string()), |_| Ok(None::<u64>)),
+        );
+
+        assert_eq!(registry.days().collect::<Vec<u8>>(), vec![1, 2]);
+        assert!(registry.get(3).is_none());
+
+        let solver = registry.get(2).unwrap();
+        assert_eq!(solver.solve_part_1("abc").unwrap(), Answer::Unsigned(3));
+        assert_eq!(solver.solve_part_2("abc").unwrap(), Answer::Signed(0));
+
+        let solver = registry.get(1).unwrap();
+        assert_eq!(
+            solver.solve_part_1("abc").unwrap(),
+            Answer::Text("abc".to_string())
+        );
+        assert_eq!(solver.solve_part_2("abc").unwrap(), Answer::Empty);
+    }
+
+    #[test]
+    fn registers_explainers_by_day() {
+        let mut registry = Registry::default();
+        registry.register_explainer(4, |input| Ok(input.to_uppercase()));
+
+        assert!(registry.explainer(3).is_none());
+
+        let explainer = registry.explainer(4).unwrap();
+        assert_eq!(explainer("abc").unwrap(), "ABC");
+    }
+}
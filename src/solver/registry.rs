@@ -0,0 +1,33 @@
+use super::Solver;
+use crate::util::Error;
+use std::collections::BTreeMap;
+
+pub type Explainer = fn(&str) -> Result<String, Error>;
+
+#[derive(Default)]
+pub struct Registry {
+    solvers: BTreeMap<u8, Box<dyn Solver>>,
+    explainers: BTreeMap<u8, Explainer>,
+}
+
+impl Registry {
+    pub fn register(&mut self, day: u8, solver: impl Solver + 'static) {
+        self.solvers.insert(day, Box::new(solver));
+    }
+
+    pub fn register_explainer(&mut self, day: u8, explainer: Explainer) {
+        self.explainers.insert(day, explainer);
+    }
+
+    pub fn get(&self, day: u8) -> Option<&dyn Solver> {
+        self.solvers.get(&day).map(|solver| solver.as_ref())
+    }
+
+    pub fn explainer(&self, day: u8) -> Option<Explainer> {
+        self.explainers.get(&day).copied()
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.solvers.keys().copied()
+    }
+}
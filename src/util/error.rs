@@ -0,0 +1,147 @@
+use std::{fmt::Display, io, str::FromStr};
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}: expected {}, found '{}'",
+            self.line, self.expected, self.text
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io { path: String, source: io::Error },
+    Parse(ParseError),
+    Runtime(Box<dyn std::error::Error>),
+}
+
+impl Error {
+    pub fn io(path: &str, source: io::Error) -> Self {
+        Error::Io {
+            path: path.to_string(),
+            source,
+        }
+    }
+
+    pub fn parse(text: &str, expected: &str) -> Self {
+        Error::Parse(ParseError {
+            line: 1,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        })
+    }
+
+    pub fn at_line(self, line: usize) -> Self {
+        match self {
+            Error::Parse(error) => Error::Parse(ParseError {
+                line: line + error.line - 1,
+                ..error
+            }),
+            error => error,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { path, source } if source.kind() == io::ErrorKind::NotFound => {
+                write!(f, "{path} not found")
+            }
+            Error::Io { path, source } => write!(f, "{path}: {source}"),
+            Error::Parse(error) => write!(f, "{error}"),
+            Error::Runtime(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(_) => None,
+            Error::Runtime(error) => Some(error.as_ref()),
+        }
+    }
+}
+
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, Error> {
+    text.parse().map_err(|_| Error::parse(text, "a number"))
+}
+
+pub fn expect_component<'a>(
+    component: Option<&'a str>,
+    text: &str,
+    expected: &str,
+) -> Result<&'a str, Error> {
+    component.ok_or(Error::parse(text, expected))
+}
+
+pub fn parse_lines<T>(
+    input: &str,
+    parser: impl Fn(&str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parser(line).map_err(|error| error.at_line(index + 1)))
+        .collect()
+}
+
+pub fn parse_blocks<T>(
+    input: &str,
+    parser: impl Fn(&str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    let mut line = 1;
+
+    input
+        .split("\n\n")
+        .map(|block| {
+            let result = parser(block).map_err(|error| error.at_line(line));
+            line += block.lines().count() + 1;
+            result
+        })
+        .collect()
+}
+
+pub fn line_after(section: &str) -> usize {
+    section.lines().count() + 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_errors() {
+        let error = Error::io(
+            "src/day_17/input.txt",
+            io::Error::from(io::ErrorKind::NotFound),
+        );
+        assert_eq!(error.to_string(), "src/day_17/input.txt not found");
+
+        let error = parse_number::<u32>("12a").unwrap_err();
+        assert_eq!(error.to_string(), "line 1: expected a number, found '12a'");
+    }
+
+    #[test]
+    fn reports_line_numbers() {
+        let error = parse_lines("1\n2\nx\n4", parse_number::<u32>).unwrap_err();
+        assert_eq!(error.to_string(), "line 3: expected a number, found 'x'");
+
+        let error = parse_blocks("1\n2\n\n3\n4a", |block| {
+            parse_lines(block, parse_number::<u32>)
+        })
+        .unwrap_err();
+        assert_eq!(error.to_string(), "line 5: expected a number, found '4a'");
+    }
+}
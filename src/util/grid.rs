@@ -0,0 +1,238 @@
+use super::{move_steps_in_direction, Direction, Error, Position};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    tiles: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, tile: T) -> Self {
+        Self {
+            tiles: vec![tile; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl Grid<char> {
+    pub fn from_characters(text: &str, allowed: &str) -> Result<Self, Error> {
+        let expected = format!("one of '{allowed}'");
+
+        Self::from_string(text, &expected, |character| {
+            match allowed.contains(character) {
+                true => Some(character),
+                false => None,
+            }
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_string(
+        text: &str,
+        expected: &str,
+        tile_from_char: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, Error> {
+        let mut tiles = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in text.lines().enumerate() {
+            let line_width = line.chars().count();
+
+            match width {
+                Some(width) if width != line_width => {
+                    return Err(
+                        Error::parse(line, &format!("a row of {width} tiles")).at_line(y + 1)
+                    );
+                }
+                _ => width = Some(line_width),
+            }
+
+            for character in line.chars() {
+                let tile = tile_from_char(character)
+                    .ok_or_else(|| Error::parse(&character.to_string(), expected).at_line(y + 1))?;
+
+                tiles.push(tile);
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            tiles,
+            width: width.unwrap_or_default(),
+            height,
+        })
+    }
+
+    pub fn map<U>(&self, tile_from: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            tiles: self.tiles.iter().map(tile_from).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: &Position) -> bool {
+        let (x, y) = *position;
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn index(&self, position: &Position) -> Option<usize> {
+        match self.contains(position) {
+            true => Some(position.1 as usize * self.width + position.0 as usize),
+            false => None,
+        }
+    }
+
+    fn position(&self, index: usize) -> Position {
+        ((index % self.width) as isize, (index / self.width) as isize)
+    }
+
+    pub fn get(&self, position: &Position) -> Option<&T> {
+        self.index(position).map(|index| &self.tiles[index])
+    }
+
+    pub fn set(&mut self, position: &Position, tile: T) -> Option<T> {
+        self.index(position)
+            .map(|index| std::mem::replace(&mut self.tiles[index], tile))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(|(index, tile)| (self.position(index), tile))
+    }
+
+    pub fn neighbours<'a, D>(
+        &'a self,
+        position: &Position,
+        directions: D,
+    ) -> impl Iterator<Item = (Position, &'a T)>
+    where
+        D: IntoIterator<Item = Direction>,
+        D::IntoIter: 'a,
+    {
+        let position = *position;
+
+        directions.into_iter().filter_map(move |direction| {
+            let next = move_steps_in_direction(&position, 1, &direction);
+            self.get(&next).map(|tile| (next, tile))
+        })
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    pub fn find(&self, tile: &T) -> Option<Position> {
+        self.tiles
+            .iter()
+            .position(|entry| entry == tile)
+            .map(|index| self.position(index))
+    }
+}
+
+impl<T: Copy> Display for Grid<T>
+where
+    char: From<T>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<String> = self
+            .tiles
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().map(|tile| char::from(*tile)).collect())
+            .collect();
+
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::from_characters("#.#\n.S.\n#.#", ".#S").unwrap()
+    }
+
+    #[test]
+    fn parses_and_renders_text() {
+        let grid = sample();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(&(1, 1)), Some(&'S'));
+        assert_eq!(grid.to_string(), "#.#\n.S.\n#.#");
+
+        let walls = grid.map(|tile| match tile {
+            '#' => '#',
+            _ => '.',
+        });
+        assert_eq!(walls.to_string(), "#.#\n...\n#.#");
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let mut grid = sample();
+
+        assert!(grid.contains(&(2, 2)));
+        assert!(!grid.contains(&(3, 0)));
+        assert!(!grid.contains(&(0, -1)));
+        assert_eq!(grid.get(&(-1, 0)), None);
+        assert_eq!(grid.set(&(0, 3), '.'), None);
+        assert_eq!(grid.set(&(0, 0), '.'), Some('#'));
+        assert_eq!(grid.get(&(0, 0)), Some(&'.'));
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = sample();
+
+        let cardinal: Vec<char> = grid
+            .neighbours(&(1, 1), Direction::cardinal())
+            .map(|(_, tile)| *tile)
+            .collect();
+        assert_eq!(cardinal, vec!['.', '.', '.', '.']);
+
+        let all = grid.neighbours(&(0, 0), Direction::all()).count();
+        assert_eq!(all, 3);
+    }
+
+    #[test]
+    fn finds_tiles() {
+        let grid = sample();
+
+        assert_eq!(grid.find(&'S'), Some((1, 1)));
+        assert_eq!(grid.find(&'E'), None);
+    }
+
+    #[test]
+    fn rejects_invalid_text() {
+        let error = Grid::from_string("..\n.", "'.'", |_| Some(())).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected a row of 2 tiles, found '.'"
+        );
+
+        let error = Grid::from_string("..\n.x", "'.'", |character| match character {
+            '.' => Some(()),
+            _ => None,
+        })
+        .unwrap_err();
+        assert_eq!(error.to_string(), "line 2: expected '.', found 'x'");
+    }
+}
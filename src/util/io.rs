@@ -1,7 +1,18 @@
-use std::fs::read_to_string;
+use super::Error;
+use std::{
+    fs::read_to_string,
+    io::{stdin, Read},
+};
 
-pub fn read_input(file_path: &str) -> String {
-    let text = read_to_string(file_path).unwrap();
+pub fn read_input(file_path: &str) -> Result<String, Error> {
+    read_to_string(file_path).map_err(|error| Error::io(file_path, error))
+}
 
-    text
-}
\ No newline at end of file
+pub fn read_stdin() -> Result<String, Error> {
+    let mut text = String::new();
+    stdin()
+        .read_to_string(&mut text)
+        .map_err(|error| Error::io("stdin", error))?;
+
+    Ok(text)
+}
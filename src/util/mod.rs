@@ -1,9 +1,17 @@
 mod direction;
+mod error;
+mod grid;
 mod io;
 mod number;
 mod position;
+mod route;
+mod search;
 
 pub use direction::Direction;
-pub use io::read_input;
+pub use error::{expect_component, line_after, parse_blocks, parse_lines, parse_number, Error};
+pub use grid::Grid;
+pub use io::{read_input, read_stdin};
 pub use number::divide_integer;
-pub use position::{move_steps_in_direction, Position};
+pub use position::{manhattan_distance, move_steps_in_direction, Position};
+pub use route::Route;
+pub use search::{a_star, bfs, dijkstra, Search};
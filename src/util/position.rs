@@ -19,3 +19,7 @@ pub fn distance_between(a: &Position, b: &Position) -> isize {
 
     (x_b - x_a).pow(2) + (y_b - y_a).pow(2)
 }
+
+pub fn manhattan_distance(a: &Position, b: &Position) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
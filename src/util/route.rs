@@ -0,0 +1,60 @@
+use super::{Position, Search};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    tiles: Vec<Position>,
+}
+
+impl Route {
+    pub fn from_tiles(tiles: Vec<Position>) -> Option<Self> {
+        match tiles.is_empty() {
+            true => None,
+            false => Some(Self { tiles }),
+        }
+    }
+
+    pub fn tiles(&self) -> &[Position] {
+        &self.tiles
+    }
+
+    pub fn steps(&self) -> impl Iterator<Item = (Position, Position)> + '_ {
+        self.tiles.windows(2).map(|pair| (pair[0], pair[1]))
+    }
+
+    pub fn cost(&self) -> usize {
+        self.tiles.len() - 1
+    }
+}
+
+impl From<Search<Position>> for Route {
+    fn from(search: Search<Position>) -> Self {
+        Self { tiles: search.path }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_tiles_and_steps() {
+        let route = Route::from_tiles(vec![(0, 0), (1, 0), (1, 1)]).unwrap();
+
+        assert_eq!(route.tiles(), &[(0, 0), (1, 0), (1, 1)]);
+        assert_eq!(
+            route.steps().collect::<Vec<_>>(),
+            vec![((0, 0), (1, 0)), ((1, 0), (1, 1))]
+        );
+        assert_eq!(route.cost(), 2);
+    }
+
+    #[test]
+    fn handles_empty_and_single_tile_routes() {
+        assert_eq!(Route::from_tiles(vec![]), None);
+
+        let route = Route::from_tiles(vec![(3, 3)]).unwrap();
+        assert_eq!(route.tiles(), &[(3, 3)]);
+        assert_eq!(route.steps().count(), 0);
+        assert_eq!(route.cost(), 0);
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+#[derive(Debug)]
+pub struct Search<S> {
+    pub cost: usize,
+    pub path: Vec<S>,
+    pub goals: Vec<S>,
+    pub predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn from_goals(start: &S, cost: usize, goals: Vec<S>, predecessors: HashMap<S, Vec<S>>) -> Self {
+        let mut path = vec![goals[0].clone()];
+
+        while path.last() != Some(start) {
+            let previous = &predecessors[path.last().unwrap()][0];
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+
+        Self {
+            cost,
+            path,
+            goals,
+            predecessors,
+        }
+    }
+
+    pub fn states_on_optimal_paths(&self) -> HashSet<S> {
+        let mut result: HashSet<S> = HashSet::new();
+        let mut pending = self.goals.clone();
+
+        while let Some(state) = pending.pop() {
+            if !result.insert(state.clone()) {
+                continue;
+            }
+
+            if let Some(previous) = self.predecessors.get(&state) {
+                pending.extend(previous.iter().cloned());
+            }
+        }
+
+        result
+    }
+}
+
+fn record_step<S: Clone + Eq + Hash>(
+    best: &mut HashMap<S, usize>,
+    predecessors: &mut HashMap<S, Vec<S>>,
+    from: &S,
+    to: &S,
+    cost: usize,
+) -> bool {
+    match best.get(to) {
+        Some(best_cost) if cost > *best_cost => false,
+        Some(best_cost) if cost == *best_cost => {
+            predecessors
+                .entry(to.clone())
+                .or_default()
+                .push(from.clone());
+            false
+        }
+        _ => {
+            best.insert(to.clone(), cost);
+            predecessors.insert(to.clone(), vec![from.clone()]);
+            true
+        }
+    }
+}
+
+pub fn bfs<S, I>(
+    start: S,
+    neighbours: impl Fn(&S) -> I,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<Search<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut best: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut queue: VecDeque<S> = VecDeque::from([start.clone()]);
+    let mut goals: Vec<S> = vec![];
+
+    while let Some(state) = queue.pop_front() {
+        let cost = best[&state];
+
+        if !goals.is_empty() && cost > best[&goals[0]] {
+            break;
+        }
+
+        if is_goal(&state) {
+            goals.push(state);
+            continue;
+        }
+
+        for next in neighbours(&state) {
+            if record_step(&mut best, &mut predecessors, &state, &next, cost + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let cost = best[goals.first()?];
+    Some(Search::from_goals(&start, cost, goals, predecessors))
+}
+
+pub fn dijkstra<S, I>(
+    start: S,
+    neighbours: impl Fn(&S) -> I,
+    cost: impl Fn(&S, &S) -> usize,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<Search<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    a_star(start, neighbours, cost, |_| 0, is_goal)
+}
+
+pub fn a_star<S, I>(
+    start: S,
+    neighbours: impl Fn(&S) -> I,
+    cost: impl Fn(&S, &S) -> usize,
+    heuristic: impl Fn(&S) -> usize,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<Search<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut best: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut states: Vec<S> = vec![start.clone()];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut goals: Vec<S> = vec![];
+
+    while let Some(Reverse((priority, state_cost, index))) = queue.pop() {
+        let state = states[index].clone();
+
+        if state_cost > best[&state] {
+            continue;
+        }
+
+        if !goals.is_empty() && priority > best[&goals[0]] {
+            break;
+        }
+
+        if is_goal(&state) {
+            if !goals.contains(&state) {
+                goals.push(state);
+            }
+
+            continue;
+        }
+
+        for next in neighbours(&state) {
+            let next_cost = state_cost + cost(&state, &next);
+
+            if record_step(&mut best, &mut predecessors, &state, &next, next_cost) {
+                queue.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+
+    let cost = best[goals.first()?];
+    Some(Search::from_goals(&start, cost, goals, predecessors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{move_steps_in_direction, Direction, Grid, Position};
+
+    fn open_tiles(grid: &Grid<char>, position: &Position) -> Vec<Position> {
+        grid.neighbours(position, Direction::cardinal())
+            .filter_map(|(next, tile)| match tile {
+                '#' => None,
+                _ => Some(next),
+            })
+            .collect()
+    }
+
+    fn sample() -> Grid<char> {
+        Grid::from_characters("S...\n.##.\n...E", ".#SE").unwrap()
+    }
+
+    #[test]
+    fn finds_shortest_path_breadth_first() {
+        let grid = sample();
+
+        let search = bfs(
+            (0, 0),
+            |position| open_tiles(&grid, position),
+            |position| position == &(3, 2),
+        )
+        .unwrap();
+
+        assert_eq!(search.cost, 5);
+        assert_eq!(search.path.len(), 6);
+        assert_eq!(search.path.first(), Some(&(0, 0)));
+        assert_eq!(search.path.last(), Some(&(3, 2)));
+        assert_eq!(search.states_on_optimal_paths().len(), 10);
+    }
+
+    #[test]
+    fn finds_cheapest_path_by_cost() {
+        let grid = sample();
+        let cost = |_: &Position, to: &Position| match grid.get(to) {
+            Some('.') if to.1 == 0 => 5,
+            _ => 1,
+        };
+
+        let search = dijkstra(
+            (0, 0),
+            |position| open_tiles(&grid, position),
+            cost,
+            |position| position == &(3, 2),
+        )
+        .unwrap();
+
+        assert_eq!(search.cost, 5);
+        assert_eq!(
+            search.path,
+            vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (3, 2)]
+        );
+        assert_eq!(search.goals, vec![(3, 2)]);
+        assert_eq!(search.predecessors[&(3, 2)], vec![(2, 2)]);
+
+        let search = a_star(
+            (0, 0),
+            |position| open_tiles(&grid, position),
+            cost,
+            |position| ((3 - position.0) + (2 - position.1)) as usize,
+            |position| position == &(3, 2),
+        )
+        .unwrap();
+
+        assert_eq!(search.cost, 5);
+    }
+
+    #[test]
+    fn searches_over_custom_states() {
+        let grid = sample();
+        let steps = |(position, direction): &(Position, Direction)| {
+            let next = move_steps_in_direction(position, 1, direction);
+
+            let mut result = vec![
+                (*position, direction.rotate_90_degress_clockwise()),
+                (*position, direction.rotate_90_degress_counter_clockwise()),
+            ];
+
+            if matches!(grid.get(&next), Some(tile) if tile != &'#') {
+                result.push((next, *direction));
+            }
+
+            result
+        };
+        let cost = |from: &(Position, Direction), to: &(Position, Direction)| match from.1 == to.1 {
+            true => 1,
+            false => 1000,
+        };
+
+        let search = dijkstra(((0, 0), Direction::E), steps, cost, |(position, _)| {
+            position == &(3, 2)
+        })
+        .unwrap();
+
+        assert_eq!(search.cost, 1005);
+        assert_eq!(search.goals.len(), 1);
+    }
+
+    #[test]
+    fn returns_none_when_unreachable() {
+        let grid = Grid::from_characters("S#E", ".#SE").unwrap();
+
+        let search = bfs(
+            (0, 0),
+            |position| open_tiles(&grid, position),
+            |position| position == &(2, 0),
+        );
+
+        assert!(search.is_none());
+    }
+}